@@ -0,0 +1,224 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{run_part, PartResult};
+use crate::{ANSI_ITALIC, ANSI_RESET};
+use std::fmt::{self, Display};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Untimed runs before sampling starts, at least 1: the first run checks that the part is solved.
+    pub warmup: u32,
+    /// Timed runs that make up the statistics.
+    pub samples: u32,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            warmup: 3,
+            samples: 10,
+        }
+    }
+}
+
+impl BenchOptions {
+    /// Reads `--bench`, `--warmup <n>` and `--samples <n>` from the command line.
+    /// Returns `None` if `--bench` is not present.
+    pub fn from_env() -> Result<Option<Self>, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_env();
+        Self::from_args(&mut args)
+    }
+
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        if !args.contains("--bench") {
+            return Ok(None);
+        }
+        Self::from_flags(args).map(Some)
+    }
+
+    /// Reads `--warmup <n>` and `--samples <n>`, falling back to the defaults.
+    pub fn from_flags(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let default = Self::default();
+        Ok(Self {
+            warmup: args
+                .opt_value_from_str("--warmup")?
+                .unwrap_or(default.warmup)
+                .max(1),
+            samples: args
+                .opt_value_from_str("--samples")?
+                .unwrap_or(default.samples)
+                .max(1),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let secs: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0_f64
+        };
+
+        Some(Self {
+            iterations: n as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, iterations: {}",
+            self.min, self.median, self.mean, self.stddev, self.iterations
+        )
+    }
+}
+
+/// The outcome of benchmarking one part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchResult {
+    /// The answer of the last run. `elapsed` holds the median sample.
+    pub result: PartResult,
+    /// `None` if the part is not solved.
+    pub stats: Option<Stats>,
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.stats {
+            Some(stats) => {
+                writeln!(f, "{}", self.result.header())?;
+                write!(
+                    f,
+                    "{} {}({}){}",
+                    self.result.answer, ANSI_ITALIC, stats, ANSI_RESET
+                )
+            }
+            None => write!(f, "{}", self.result),
+        }
+    }
+}
+
+/// Runs one part of a solution `options.warmup` times untimed, then `options.samples` times timed.
+/// Parts that are not solved are only run once, as the first warmup run.
+pub fn bench_part<T: Display>(
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    options: &BenchOptions,
+) -> BenchResult {
+    let first = run_part(day, part, &func, input);
+    if !first.is_solved() {
+        return BenchResult {
+            result: first,
+            stats: None,
+        };
+    }
+
+    // the first run doubles as a warmup run.
+    for _ in 1..options.warmup {
+        run_part(day, part, &func, input);
+    }
+
+    let mut result = first;
+    let mut samples = Vec::with_capacity(options.samples as usize);
+    for _ in 0..options.samples {
+        result = run_part(day, part, &func, input);
+        samples.push(result.elapsed);
+    }
+
+    let stats = Stats::from_samples(&samples);
+    if let Some(stats) = &stats {
+        result.elapsed = stats.median;
+    }
+    BenchResult { result, stats }
+}
+
+pub fn print_bench(bench: &BenchResult) {
+    println!("{}", bench);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev.as_nanos(), 1291);
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_part() {
+        let options = BenchOptions {
+            warmup: 2,
+            samples: 5,
+        };
+        let bench = bench_part(1, 1, |input| Some(input.len()), "abc", &options);
+        assert_eq!(bench.result.answer, "3");
+        assert_eq!(bench.stats.unwrap().iterations, 5);
+
+        let bench = bench_part(1, 2, |_| None::<u32>, "abc", &options);
+        assert!(!bench.result.is_solved());
+        assert_eq!(bench.stats, None);
+    }
+
+    #[test]
+    fn test_options_from_args() {
+        let mut args =
+            pico_args::Arguments::from_vec(vec!["--bench".into(), "--samples".into(), "3".into()]);
+        let options = BenchOptions::from_args(&mut args).unwrap().unwrap();
+        assert_eq!(options.samples, 3);
+        assert_eq!(options.warmup, 3);
+
+        // the first run is always a warmup run.
+        let mut args =
+            pico_args::Arguments::from_vec(vec!["--bench".into(), "--warmup".into(), "0".into()]);
+        let options = BenchOptions::from_args(&mut args).unwrap().unwrap();
+        assert_eq!(options.warmup, 1);
+
+        let mut args = pico_args::Arguments::from_vec(vec![]);
+        assert_eq!(BenchOptions::from_args(&mut args).unwrap(), None);
+    }
+}
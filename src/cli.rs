@@ -0,0 +1,587 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::BenchOptions;
+use crate::error::Error;
+use crate::input::InputSource;
+use crate::report::Format;
+use crate::scaffold::Mode;
+use crate::template::DEFAULT_TEMPLATE;
+use crate::DEFAULT_YEAR;
+use pico_args::Arguments;
+use std::env;
+use std::ffi::OsString;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+const HELP: &str = "\
+Solve Advent of Code puzzles.
+
+Usage: aoc [OPTIONS] <COMMAND>
+
+Commands:
+  scaffold <day>       Create the files of a new day
+  download <day>       Download the input and the puzzle description of a day
+  solve <day>          Run the solution of a day
+  all [day]            Run all solutions
+  bench [day]          Benchmark solutions, or compare benchmarks with --compare
+  verify [day]         Check the answers of all solutions against the known answers
+  submit <day> <part>  Submit the answer of a part
+  progress             Update the stars and benchmark tables in README.md
+  leaderboard [id]     Show a private leaderboard
+
+Options:
+  -y, --year <year>    The event to work on (default: DEFAULT_YEAR)
+      --input <path>   Read the puzzle input from <path>, or from stdin with `-`
+      --color <when>   Color the output: auto, always or never (default: auto)
+  -h, --help           Print help, e.g. `aoc solve --help`
+";
+
+const SCAFFOLD_HELP: &str = "\
+Create the module, binary, input and example files of a day.
+
+Usage: aoc scaffold <day> [OPTIONS]
+
+Options:
+  -t, --template <name>  The template in templates/ to start from (default: default)
+      --puzzle <path>    A saved puzzle page to take the example and its answers from
+      --dry-run          Print what would change without writing anything
+      --force            Regenerate an existing day, backing up its module
+      --add-missing      Only add what's missing to an existing day
+";
+
+const DOWNLOAD_HELP: &str = "\
+Download the input and the puzzle description of a day.
+
+Usage: aoc download <day> [OPTIONS]
+
+Options:
+  -f, --force  Download the input again, even if it was downloaded before
+";
+
+const SOLVE_HELP: &str = "\
+Run the solution of a day against its inputs.
+
+Usage: aoc solve <day> [OPTIONS]
+
+Options:
+      --format <format>  Print json, csv or junit instead of the human-readable output
+      --timeout <secs>   Stop parts that run longer than this, 0 to disable (default: 60)
+";
+
+const ALL_HELP: &str = "\
+Run all solutions, or all solutions of a day.
+
+Usage: aoc all [day] [OPTIONS]
+
+Options:
+      --format <format>  Print json, csv or junit instead of the human-readable output
+      --timeout <secs>   Stop parts that run longer than this, 0 to disable (default: 60)
+";
+
+const BENCH_HELP: &str = "\
+Benchmark solutions and record their timings in bench_history.csv.
+
+Usage: aoc bench [day] [OPTIONS]
+       aoc bench --compare [OPTIONS]
+
+Options:
+      --warmup <n>             Untimed runs before sampling, at least 1 (default: 3)
+      --samples <n>            Timed runs of each part (default: 10)
+      --format <format>        Print json, csv or junit instead of the human-readable output
+      --timeout <secs>         Stop parts that run longer than this, 0 to disable (default: 60)
+      --compare                Compare the latest benchmarked commit against a baseline
+      --baseline <commit>      The commit to compare against (default: the previous one)
+      --threshold <percent>    How much slower a part may get (default: 10)
+";
+
+const VERIFY_HELP: &str = "\
+Run all solutions against their inputs and check the answers against the known answers.
+
+Usage: aoc verify [day] [OPTIONS]
+
+Options:
+      --record          Add the answers of parts without a known answer
+      --timeout <secs>  Stop parts that run longer than this, 0 to disable (default: 60)
+";
+
+const SUBMIT_HELP: &str = "\
+Run a part against the input of its day and submit the answer.
+
+Usage: aoc submit <day> <part>
+";
+
+const PROGRESS_HELP: &str = "\
+Run the days of a year to find out which parts earned their star, and update the stars
+and benchmark tables in README.md. Benchmarks are taken from bench_history.csv.
+
+Usage: aoc progress [OPTIONS]
+
+Options:
+      --dry-run         Print the tables instead of updating README.md
+      --timeout <secs>  Stop parts that run longer than this, 0 to disable (default: 60)
+";
+
+const LEADERBOARD_HELP: &str = "\
+Show the members of a private leaderboard with their stars and local score, or their
+completion times of a day. The leaderboard is downloaded at most every 15 minutes.
+
+Usage: aoc leaderboard [id] [OPTIONS]
+
+Options:
+      --day <day>    Show the completion times of a day and the deltas between its parts
+      --file <path>  Read a leaderboard JSON file instead of downloading it
+
+The id defaults to the AOC_LEADERBOARD_ID environment variable.
+";
+
+/// When to color the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Only if stdout is a terminal and `NO_COLOR` is not set.
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Color::Auto),
+            "always" => Ok(Color::Always),
+            "never" => Ok(Color::Never),
+            _ => Err(format!(
+                "unknown color \"{}\", expected one of: auto, always, never",
+                s
+            )),
+        }
+    }
+}
+
+impl Color {
+    pub fn enabled(self) -> bool {
+        match self {
+            Color::Auto => io::stdout().is_terminal() && env::var_os("NO_COLOR").is_none(),
+            Color::Always => true,
+            Color::Never => false,
+        }
+    }
+}
+
+/// Options that every command accepts.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Global {
+    /// `None` if no year was given, which selects all years where that makes sense.
+    pub year: Option<u16>,
+    pub input: Option<InputSource>,
+    pub color: Color,
+}
+
+impl Global {
+    pub fn year(&self) -> u16 {
+        self.year.unwrap_or(DEFAULT_YEAR)
+    }
+}
+
+/// Options of the commands that run solutions: `solve`, `all` and `bench`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunArgs {
+    pub day: Option<u8>,
+    pub format: Option<Format>,
+    /// `None` if parts may run indefinitely.
+    pub timeout: Option<Duration>,
+    /// Set for `bench`.
+    pub bench: Option<BenchOptions>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Help(String),
+    Scaffold {
+        day: u8,
+        template: String,
+        puzzle: Option<PathBuf>,
+        mode: Mode,
+        dry_run: bool,
+    },
+    Download {
+        day: u8,
+        force: bool,
+    },
+    Run(RunArgs),
+    Compare {
+        baseline: Option<String>,
+        threshold: f64,
+    },
+    Verify {
+        day: Option<u8>,
+        record: bool,
+        timeout: Option<Duration>,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+    },
+    Progress {
+        timeout: Option<Duration>,
+        dry_run: bool,
+    },
+    Leaderboard {
+        id: Option<u64>,
+        file: Option<PathBuf>,
+        day: Option<u8>,
+    },
+    /// Runs one part against the input on stdin, see [`crate::runner::isolate_in_subprocesses`].
+    /// Not listed in the help, as it is only meant to be started by `aoc` itself.
+    RunPart {
+        day: u8,
+        part: u8,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cli {
+    pub global: Global,
+    pub command: Command,
+}
+
+fn help(text: &str) -> Command {
+    Command::Help(text.replace("DEFAULT_YEAR", &DEFAULT_YEAR.to_string()))
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", value)),
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err(format!("\"{}\" is not a part, expected 1 or 2", value)),
+    }
+}
+
+/// Turns a timeout in seconds into a duration, where `0` disables the timeout.
+fn parse_timeout(value: &str) -> Result<Option<Duration>, String> {
+    let seconds: f64 = value.parse().map_err(|e| format!("{}", e))?;
+    if seconds == 0_f64 {
+        return Ok(None);
+    }
+    Duration::try_from_secs_f64(seconds)
+        .map(Some)
+        .map_err(|e| format!("invalid timeout: {}", e))
+}
+
+/// A required positional argument, with a readable error if it is missing.
+fn required<T>(
+    args: &mut Arguments,
+    name: &str,
+    parse: fn(&str) -> Result<T, String>,
+) -> Result<T, Error> {
+    args.free_from_fn(parse).map_err(|e| match e {
+        pico_args::Error::MissingArgument => Error::Args(format!("missing <{}>", name)),
+        e => e.into(),
+    })
+}
+
+/// A day given as `[day]` or `--day <day>`.
+/// Unknown flags are not taken for the day, so they are reported as such.
+fn optional_day(args: &mut Arguments) -> Result<Option<u8>, Error> {
+    if let Some(day) = args.opt_value_from_fn("--day", parse_day)? {
+        return Ok(Some(day));
+    }
+    match args.opt_free_from_os_str(|s| Ok::<_, String>(s.to_os_string()))? {
+        Some(arg) if arg.to_string_lossy().starts_with('-') => Err(unexpected(&[arg])),
+        Some(arg) => Ok(Some(
+            parse_day(&arg.to_string_lossy()).map_err(Error::Args)?,
+        )),
+        None => Ok(None),
+    }
+}
+
+fn unexpected(rest: &[OsString]) -> Error {
+    let rest: Vec<String> = rest
+        .iter()
+        .map(|arg| arg.to_string_lossy().to_string())
+        .collect();
+    Error::Args(format!("unexpected arguments: {}", rest.join(" ")))
+}
+
+fn timeout(args: &mut Arguments) -> Result<Option<Duration>, Error> {
+    Ok(args
+        .opt_value_from_fn("--timeout", parse_timeout)?
+        .unwrap_or(Some(Duration::from_secs(60))))
+}
+
+fn scaffold(args: &mut Arguments) -> Result<Command, Error> {
+    let mode = match (args.contains("--force"), args.contains("--add-missing")) {
+        (false, false) => Mode::New,
+        (true, false) => Mode::Force,
+        (false, true) => Mode::AddMissing,
+        (true, true) => {
+            return Err(Error::Args(
+                "--force and --add-missing can't be combined".to_string(),
+            ))
+        }
+    };
+    Ok(Command::Scaffold {
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        puzzle: args.opt_value_from_os_str("--puzzle", |s| Ok::<_, String>(PathBuf::from(s)))?,
+        dry_run: args.contains("--dry-run"),
+        mode,
+        day: required(args, "day", parse_day)?,
+    })
+}
+
+/// Free arguments are taken from the front, so the day is parsed after all options.
+fn run(
+    args: &mut Arguments,
+    day_required: bool,
+    bench: Option<BenchOptions>,
+) -> Result<Command, Error> {
+    let format = args.opt_value_from_str("--format")?;
+    let timeout = timeout(args)?;
+    let day = if day_required {
+        Some(required(args, "day", parse_day)?)
+    } else {
+        optional_day(args)?
+    };
+    Ok(Command::Run(RunArgs {
+        day,
+        format,
+        timeout,
+        bench,
+    }))
+}
+
+fn bench(args: &mut Arguments) -> Result<Command, Error> {
+    if args.contains("--compare") {
+        return Ok(Command::Compare {
+            baseline: args.opt_value_from_str("--baseline")?,
+            threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        });
+    }
+    let options = BenchOptions::from_flags(args)?;
+    run(args, false, Some(options))
+}
+
+/// Parses the command line, without the program name.
+pub fn parse(args: Vec<OsString>) -> Result<Cli, Error> {
+    let mut args = Arguments::from_vec(args);
+    let wants_help = args.contains(["-h", "--help"]);
+    let mut global = Global {
+        year: args.opt_value_from_str(["-y", "--year"])?,
+        input: InputSource::from_args(&mut args)?,
+        color: args.opt_value_from_str("--color")?.unwrap_or(Color::Auto),
+    };
+
+    let name: Option<String> = args.subcommand()?;
+    let command = match (name.as_deref(), wants_help) {
+        (None, _) | (Some("help"), _) => help(HELP),
+        (Some("scaffold"), true) => help(SCAFFOLD_HELP),
+        (Some("download"), true) => help(DOWNLOAD_HELP),
+        (Some("solve"), true) => help(SOLVE_HELP),
+        (Some("all"), true) => help(ALL_HELP),
+        (Some("bench"), true) => help(BENCH_HELP),
+        (Some("verify"), true) => help(VERIFY_HELP),
+        (Some("submit"), true) => help(SUBMIT_HELP),
+        (Some("progress"), true) => help(PROGRESS_HELP),
+        (Some("leaderboard"), true) => help(LEADERBOARD_HELP),
+        (Some("scaffold"), false) => scaffold(&mut args)?,
+        (Some("download"), false) => Command::Download {
+            force: args.contains(["-f", "--force"]),
+            day: required(&mut args, "day", parse_day)?,
+        },
+        (Some("solve"), false) => {
+            // a single day is of the default year unless another year is given.
+            global.year = Some(global.year());
+            run(&mut args, true, None)?
+        }
+        (Some("all"), false) => run(&mut args, false, None)?,
+        (Some("bench"), false) => bench(&mut args)?,
+        (Some("verify"), false) => Command::Verify {
+            record: args.contains("--record"),
+            timeout: timeout(&mut args)?,
+            day: optional_day(&mut args)?,
+        },
+        (Some("submit"), false) => Command::Submit {
+            day: required(&mut args, "day", parse_day)?,
+            part: required(&mut args, "part", parse_part)?,
+        },
+        (Some("progress"), false) => Command::Progress {
+            timeout: timeout(&mut args)?,
+            dry_run: args.contains("--dry-run"),
+        },
+        (Some("leaderboard"), false) => Command::Leaderboard {
+            day: args.opt_value_from_fn("--day", parse_day)?,
+            file: args.opt_value_from_os_str("--file", |s| Ok::<_, String>(PathBuf::from(s)))?,
+            id: args.opt_free_from_str()?,
+        },
+        (Some("run-part"), _) => {
+            global.year = Some(global.year());
+            Command::RunPart {
+                day: required(&mut args, "day", parse_day)?,
+                part: required(&mut args, "part", parse_part)?,
+            }
+        }
+        (Some(name), _) => {
+            return Err(Error::Args(format!(
+                "unknown command \"{}\". Run `aoc --help` to list all commands.",
+                name
+            )))
+        }
+    };
+
+    let rest = args.finish();
+    if !rest.is_empty() && !matches!(command, Command::Help(_)) {
+        return Err(unexpected(&rest));
+    }
+
+    Ok(Cli { global, command })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(args: &str) -> Result<Cli, Error> {
+        parse(args.split_whitespace().map(OsString::from).collect())
+    }
+
+    #[test]
+    fn test_global_flags() {
+        let cli = parse_str("--year 2021 all 3 --color never").unwrap();
+        assert_eq!(cli.global.year, Some(2021));
+        assert_eq!(cli.global.color, Color::Never);
+        assert_eq!(
+            cli.command,
+            Command::Run(RunArgs {
+                day: Some(3),
+                format: None,
+                timeout: Some(Duration::from_secs(60)),
+                bench: None,
+            })
+        );
+
+        let cli = parse_str("solve 7 --input - --timeout 0 -y 2020").unwrap();
+        assert_eq!(cli.global.input, Some(InputSource::Stdin));
+        assert_eq!(cli.global.year, Some(2020));
+        assert!(matches!(
+            cli.command,
+            Command::Run(RunArgs {
+                day: Some(7),
+                timeout: None,
+                ..
+            })
+        ));
+        // `solve` runs a day of the default year, `all` runs every year.
+        assert_eq!(
+            parse_str("solve 7").unwrap().global.year,
+            Some(DEFAULT_YEAR)
+        );
+        assert_eq!(parse_str("all").unwrap().global.year, None);
+    }
+
+    #[test]
+    fn test_commands() {
+        assert_eq!(
+            parse_str("scaffold 4 -t grid --add-missing --dry-run")
+                .unwrap()
+                .command,
+            Command::Scaffold {
+                day: 4,
+                template: "grid".to_string(),
+                puzzle: None,
+                mode: Mode::AddMissing,
+                dry_run: true,
+            }
+        );
+        assert_eq!(
+            parse_str("download -f 1").unwrap().command,
+            Command::Download {
+                day: 1,
+                force: true
+            }
+        );
+        assert_eq!(
+            parse_str("submit 1 2").unwrap().command,
+            Command::Submit { day: 1, part: 2 }
+        );
+        assert_eq!(
+            parse_str("progress --dry-run --timeout 0").unwrap().command,
+            Command::Progress {
+                timeout: None,
+                dry_run: true,
+            }
+        );
+        assert_eq!(
+            parse_str("leaderboard --day 3 123456").unwrap().command,
+            Command::Leaderboard {
+                id: Some(123456),
+                file: None,
+                day: Some(3),
+            }
+        );
+        assert_eq!(
+            parse_str("bench --compare --threshold 5").unwrap().command,
+            Command::Compare {
+                baseline: None,
+                threshold: 5_f64
+            }
+        );
+        assert!(matches!(
+            parse_str("bench 16 --samples 50 --format csv")
+                .unwrap()
+                .command,
+            Command::Run(RunArgs {
+                day: Some(16),
+                format: Some(Format::Csv),
+                bench: Some(BenchOptions { samples: 50, .. }),
+                ..
+            })
+        ));
+        assert_eq!(
+            parse_str("verify --day 2 --record").unwrap().command,
+            Command::Verify {
+                day: Some(2),
+                record: true,
+                timeout: Some(Duration::from_secs(60)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_help() {
+        assert!(
+            matches!(parse_str("").unwrap().command, Command::Help(text) if text.contains("Commands:"))
+        );
+        assert!(
+            matches!(parse_str("solve --help").unwrap().command, Command::Help(text) if text.starts_with("Run the solution of a day"))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let message = |args: &str| parse_str(args).unwrap_err().to_string();
+        assert_eq!(message("solve"), "missing <day>");
+        assert!(message("solve 26").contains("\"26\" is not a day between 1 and 25"));
+        assert!(message("submit 1 3").contains("expected 1 or 2"));
+        assert!(message("frobnicate").starts_with("unknown command \"frobnicate\""));
+        assert_eq!(message("all --verbose"), "unexpected arguments: --verbose");
+        assert_eq!(
+            message("scaffold 1 --force --add-missing"),
+            "--force and --add-missing can't be combined"
+        );
+        assert_eq!(parse_str("solve").unwrap_err().exit_code(), 2);
+    }
+}
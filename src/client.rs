@@ -0,0 +1,409 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::submit::{self, Response};
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use std::{env, fs, io};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable holding the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable holding the id of the private leaderboard `aoc leaderboard` shows.
+pub const LEADERBOARD_ENV: &str = "AOC_LEADERBOARD_ID";
+/// Environment variable that points the client at another server, e.g. a local stand-in.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+const SESSION_FILE: &str = ".adventofcode.session";
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The server rejected the session cookie.
+    SessionExpired,
+    NotReleased {
+        year: u16,
+        day: u8,
+    },
+    Http {
+        status: u16,
+        body: String,
+    },
+    /// A page that doesn't contain any of the expected messages.
+    UnexpectedPage,
+    /// The server answered with a page instead of the leaderboard, e.g. because the id is wrong.
+    NoLeaderboard {
+        year: u16,
+        id: u64,
+    },
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found. Set {} or paste your session cookie into \"~/{}\".",
+                SESSION_ENV, SESSION_FILE
+            ),
+            ClientError::SessionExpired => write!(
+                f,
+                "the session cookie was rejected, it has probably expired. Log in again and update your session cookie."
+            ),
+            ClientError::NotReleased { year, day } => write!(
+                f,
+                "day {} of {} is not released yet. Puzzles unlock at midnight EST.",
+                day, year
+            ),
+            ClientError::Http { status, body } => {
+                write!(f, "unexpected response ({}): {}", status, body.trim())
+            }
+            ClientError::UnexpectedPage => write!(
+                f,
+                "could not understand the response. Check the puzzle page to see what happened."
+            ),
+            ClientError::NoLeaderboard { year, id } => write!(
+                f,
+                "could not get private leaderboard {} of {}. Check its id and that you are a member.",
+                id, year
+            ),
+            ClientError::Transport(e) => write!(f, "request failed: {}", e),
+            ClientError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+fn session_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Reads the session cookie from `AOC_SESSION` or `~/.adventofcode.session`.
+pub fn load_session() -> Result<String, ClientError> {
+    let session = match env::var(SESSION_ENV) {
+        Ok(session) if !session.trim().is_empty() => session,
+        _ => session_path()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .unwrap_or_default(),
+    };
+    match session.trim() {
+        "" => Err(ClientError::MissingSession),
+        session => Ok(session.to_string()),
+    }
+}
+
+/// An authenticated client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// A client using the session cookie from the environment and `AOC_BASE_URL`, if set.
+    pub fn from_env() -> Result<Self, ClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| BASE_URL.to_string());
+        Ok(Self::new(&base_url, &load_session()?))
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn get(&self, path: &str) -> Result<String, ClientError> {
+        read_response(self.request("GET", path).call())
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+            .map_err(|e| match e {
+                ClientError::Http { status: 404, .. } => ClientError::NotReleased { year, day },
+                e => e,
+            })
+    }
+
+    /// The HTML of a puzzle page. It contains part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        self.get(&format!("/{}/day/{}", year, day))
+            .map_err(|e| match e {
+                ClientError::Http { status: 404, .. } => ClientError::NotReleased { year, day },
+                e => e,
+            })
+    }
+
+    /// The JSON of a private leaderboard. Please don't fetch it more than every 15 minutes.
+    pub fn leaderboard(&self, year: u16, id: u64) -> Result<String, ClientError> {
+        let json = self
+            .get(&format!("/{}/leaderboard/private/view/{}.json", year, id))
+            .map_err(|e| match e {
+                ClientError::Http { status: 404, .. } => ClientError::NoLeaderboard { year, id },
+                e => e,
+            })?;
+        // the server redirects to an HTML page for leaderboards the session can't see.
+        match json.trim_start().starts_with('{') {
+            true => Ok(json),
+            false => Err(ClientError::NoLeaderboard { year, id }),
+        }
+    }
+
+    /// Posts an answer and parses the server's verdict.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Response, ClientError> {
+        let request = self.request("POST", &format!("/{}/day/{}/answer", year, day));
+        let html = read_response(
+            request.send_form(&[("level", part.to_string().as_str()), ("answer", answer)]),
+        )?;
+        submit::parse_response(&html).ok_or(ClientError::UnexpectedPage)
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, ClientError> {
+    let (status, body) = match response {
+        Ok(response) => (response.status(), response.into_string()?),
+        Err(ureq::Error::Status(status, response)) => {
+            (status, response.into_string().unwrap_or_default())
+        }
+        Err(e) => return Err(ClientError::Transport(e.to_string())),
+    };
+
+    match status {
+        200..=299 => Ok(body),
+        // the server responds with "Puzzle inputs differ by user. Please log in to get your puzzle input."
+        400 | 500 if body.contains("log in") => Err(ClientError::SessionExpired),
+        _ => Err(ClientError::Http { status, body }),
+    }
+}
+
+/// Whether an input was downloaded to `path` before. Scaffolded input files are empty.
+pub fn is_downloaded(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.len() > 0)
+}
+
+/// Downloads the input of `day` to `path`. Inputs that were downloaded before are not
+/// fetched again unless `force` is set. Returns whether the input was downloaded.
+pub fn download_input(
+    client: &Client,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+) -> Result<bool, ClientError> {
+    if is_downloaded(path) && !force {
+        return Ok(false);
+    }
+
+    let input = client.input(year, day)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, input)?;
+    Ok(true)
+}
+
+/// A stand-in for the Advent of Code server that answers requests with canned responses.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Serves one `(status, body)` response per request and sends each request it receives,
+    /// e.g. `"GET /2022/day/1/input\nCookie: session=abc\n\n"`, to the returned receiver.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut request: String = line.rsplit_once(' ').unwrap().0.to_string() + "\n";
+                let mut length = 0;
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    let header = line.trim_end().to_lowercase();
+                    if header.is_empty() {
+                        break;
+                    }
+                    if let Some(value) = header.strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    if header.starts_with("cookie:") || header.starts_with("content-type:") {
+                        request += &(line.trim_end().to_string() + "\n");
+                    }
+                }
+                let mut body_in = vec![0; length];
+                reader.read_exact(&mut body_in).unwrap();
+                request += &format!("\n{}", String::from_utf8_lossy(&body_in));
+                sender.send(request).unwrap();
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, receiver)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_server::serve;
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (base_url, requests) = serve(vec![(200, "1000\n2000\n")]);
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(client.input(2022, 1).unwrap(), "1000\n2000\n");
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2022/day/1/input\nCookie: session=abc\n\n"
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let (base_url, _requests) = serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (503, "Service Unavailable"),
+        ]);
+        let client = Client::new(&base_url, "abc");
+        assert!(matches!(
+            client.input(2022, 1),
+            Err(ClientError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.input(2022, 25),
+            Err(ClientError::NotReleased {
+                year: 2022,
+                day: 25
+            })
+        ));
+        assert!(matches!(
+            client.input(2022, 2),
+            Err(ClientError::Http { status: 503, .. })
+        ));
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let (base_url, requests) = serve(vec![(200, "<article>...</article>"), (404, "")]);
+        let client = Client::new(&base_url, "abc");
+        assert_eq!(client.puzzle(2022, 3).unwrap(), "<article>...</article>");
+        assert!(requests.recv().unwrap().starts_with("GET /2022/day/3\n"));
+        assert!(matches!(
+            client.puzzle(2022, 4),
+            Err(ClientError::NotReleased { .. })
+        ));
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, requests) = serve(vec![
+            (200, "<article><p>That's the right answer!</p></article>"),
+            (200, "<html>Advent of Code</html>"),
+        ]);
+        let client = Client::new(&base_url, "abc");
+        let response = client.submit(2022, 5, 2, "MCD").unwrap();
+        assert_eq!(response.outcome, submit::Outcome::Correct);
+        assert_eq!(
+            requests.recv().unwrap(),
+            "POST /2022/day/5/answer\nCookie: session=abc\nContent-Type: application/x-www-form-urlencoded\n\nlevel=2&answer=MCD"
+        );
+        assert!(matches!(
+            client.submit(2022, 5, 2, "MCD"),
+            Err(ClientError::UnexpectedPage)
+        ));
+    }
+
+    #[test]
+    fn test_leaderboard() {
+        let (base_url, requests) = serve(vec![
+            (200, "{\"owner_id\":1,\"event\":\"2022\",\"members\":{}}"),
+            (200, "<html>[Log In]</html>"),
+        ]);
+        let client = Client::new(&base_url, "abc");
+        assert!(client
+            .leaderboard(2022, 1)
+            .unwrap()
+            .contains("\"owner_id\":1"));
+        assert_eq!(
+            requests.recv().unwrap(),
+            "GET /2022/leaderboard/private/view/1.json\nCookie: session=abc\n\n"
+        );
+        assert!(matches!(
+            client.leaderboard(2022, 2),
+            Err(ClientError::NoLeaderboard { year: 2022, id: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_download_input() {
+        let (base_url, requests) = serve(vec![(200, "input\n")]);
+        let client = Client::new(&base_url, "abc");
+        let path = env::temp_dir()
+            .join(format!("aoc-download-{}", std::process::id()))
+            .join("01.txt");
+
+        assert!(download_input(&client, 2022, 1, &path, false).unwrap());
+        // the second download is served from the file.
+        assert!(!download_input(&client, 2022, 1, &path, false).unwrap());
+        assert_eq!(fs::read_to_string(&path).unwrap(), "input\n");
+        assert_eq!(requests.try_iter().count(), 1);
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
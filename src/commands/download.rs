@@ -0,0 +1,45 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::Global;
+use crate::client::{self, Client};
+use crate::error::Error;
+use crate::puzzle;
+use std::fs;
+
+/// Downloads the input of a day, unless it was downloaded before, and its puzzle description.
+pub fn download(global: &Global, day: u8, force: bool) -> Result<(), Error> {
+    let year = global.year();
+    let input_path = crate::file_path("inputs", year, day);
+    let client = Client::from_env()?;
+
+    if client::is_downloaded(&input_path) && !force {
+        println!(
+            "Input was already downloaded to \"{}\". Pass --force to download it again.",
+            input_path.display()
+        );
+    } else {
+        println!("Downloading input for day {}, {}...", day, year);
+        client::download_input(&client, year, day, &input_path, true)?;
+        println!(
+            "🎄 Successfully wrote input to \"{}\".",
+            input_path.display()
+        );
+    }
+
+    // the puzzle is fetched every time, since part two unlocks after solving part one.
+    println!("Downloading puzzle for day {}, {}...", day, year);
+    let puzzle_path = puzzle::puzzle_path(year, day);
+    let markdown = puzzle::to_markdown(&client.puzzle(year, day)?);
+    puzzle_path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&puzzle_path, markdown))
+        .map_err(|e| Error::io(&puzzle_path, e))?;
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,74 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::Global;
+use crate::client::{Client, LEADERBOARD_ENV};
+use crate::error::Error;
+use crate::leaderboard::{self, Leaderboard};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use std::{env, fs};
+
+/// The Advent of Code website asks not to fetch a leaderboard more often than this.
+const CACHE_DURATION: Duration = Duration::from_secs(15 * 60);
+
+/// Reads a leaderboard from `file`, or downloads it unless it was downloaded less than
+/// 15 minutes ago. Returns the JSON and the file it is kept in.
+fn load(year: u16, id: Option<u64>, file: Option<&Path>) -> Result<(String, PathBuf), Error> {
+    if let Some(path) = file {
+        let json = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+        return Ok((json, path.to_path_buf()));
+    }
+
+    let id = match id {
+        Some(id) => id,
+        None => env::var(LEADERBOARD_ENV)
+            .ok()
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| {
+                Error::Args(format!(
+                    "pass the id of a private leaderboard, set {} or pass --file <path>",
+                    LEADERBOARD_ENV
+                ))
+            })?,
+    };
+    let path = leaderboard::cache_path(year, id);
+    let age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if let Some(age) = age.filter(|age| *age < CACHE_DURATION) {
+        println!(
+            "Showing the leaderboard downloaded {} minutes ago.\n",
+            age.as_secs() / 60
+        );
+        let json = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        return Ok((json, path));
+    }
+
+    let json = Client::from_env()?.leaderboard(year, id)?;
+    path.parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, &json))
+        .map_err(|e| Error::io(&path, e))?;
+    Ok((json, path))
+}
+
+/// Prints the members of a private leaderboard, or their completion times of `day`.
+pub fn leaderboard(
+    global: &Global,
+    id: Option<u64>,
+    file: Option<&Path>,
+    day: Option<u8>,
+) -> Result<(), Error> {
+    let (json, path) = load(global.year(), id, file)?;
+    let leaderboard =
+        Leaderboard::parse(&json).map_err(|message| Error::Parse { path, message })?;
+    let year = leaderboard.year(global.year());
+    match day {
+        Some(day) => print!("{}", leaderboard::render_day(&leaderboard, year, day)),
+        None => print!("{}", leaderboard::render_overview(&leaderboard, year)),
+    }
+    Ok(())
+}
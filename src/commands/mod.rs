@@ -0,0 +1,96 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::{Cli, Command, Global};
+use crate::error::Error;
+use crate::input::Input;
+use crate::runner;
+use crate::solutions::{Solution, SOLUTIONS};
+
+pub mod download;
+pub mod leaderboard;
+pub mod progress;
+pub mod run;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+
+/// Runs a parsed command line.
+pub fn execute(cli: Cli) -> Result<(), Error> {
+    crate::set_color(cli.global.color.enabled());
+    let global = &cli.global;
+    match cli.command {
+        Command::Help(text) => {
+            print!("{}", text);
+            Ok(())
+        }
+        Command::Scaffold {
+            day,
+            template,
+            puzzle,
+            mode,
+            dry_run,
+        } => scaffold::scaffold(global, day, &template, puzzle.as_deref(), mode, dry_run),
+        Command::Download { day, force } => download::download(global, day, force),
+        Command::Run(args) => run::run(global, &args),
+        Command::Compare {
+            baseline,
+            threshold,
+        } => run::compare(baseline.as_deref(), threshold),
+        Command::Verify {
+            day,
+            record,
+            timeout,
+        } => verify::verify(global, day, record, timeout),
+        Command::Submit { day, part } => submit::submit(global, day, part),
+        Command::Progress { timeout, dry_run } => progress::progress(global, timeout, dry_run),
+        Command::Leaderboard { id, file, day } => {
+            leaderboard::leaderboard(global, id, file.as_deref(), day)
+        }
+        Command::RunPart { day, part } => run_part(global, day, part),
+    }
+}
+
+/// Runs one part for the process that started this one, see [`crate::runner::serve_part`].
+fn run_part(global: &Global, day: u8, part: u8) -> Result<(), Error> {
+    let solution = selected_solutions(global, Some(day))?
+        .pop()
+        .ok_or_else(|| Error::Failed(format!("no solution for day {:02}.", day)))?;
+    let (_, solver) = solution.parts()[usize::from(part - 1)];
+    runner::serve_part(day, part, solver).map_err(|error| Error::Io {
+        path: "<stdin>".into(),
+        error,
+    })
+}
+
+/// The registered solutions matching `--year` and `day`. An `--input` can only be given
+/// for a single solution.
+fn selected_solutions(global: &Global, day: Option<u8>) -> Result<Vec<&'static Solution>, Error> {
+    let solutions: Vec<&Solution> = SOLUTIONS
+        .iter()
+        .filter(|solution| global.year.is_none_or(|year| solution.year == year))
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+        .collect();
+    if global.input.is_some() && solutions.len() != 1 {
+        return Err(Error::Args(
+            "an input can only be given for a single day. Pass a day (and --year) to select one."
+                .to_string(),
+        ));
+    }
+    Ok(solutions)
+}
+
+/// Reads the inputs of a solution: the one given with `--input`, or the default and all named inputs.
+/// A missing input file is not an error, the day is just not solved.
+fn read_inputs(global: &Global, solution: &Solution) -> Result<Vec<Input>, Error> {
+    let inputs = match &global.input {
+        Some(source) => crate::read_input(source).map(|text| vec![Input::unnamed(text)]),
+        None => crate::read_inputs("inputs", solution.year, solution.day),
+    };
+    match inputs {
+        Ok(inputs) => Ok(inputs),
+        Err(e) if e.is_missing() => Ok(vec![]),
+        Err(e) => Err(e.into()),
+    }
+}
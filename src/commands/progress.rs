@@ -0,0 +1,84 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::Global;
+use crate::error::Error;
+use crate::history::{self, History};
+use crate::input::InputSource;
+use crate::progress::{self, DayProgress, BENCH_MARKER, STARS_MARKER};
+use crate::solutions::SOLUTIONS;
+use crate::verify::{self, Answers, Verdict};
+use crate::{runner, ANSI_BOLD, ANSI_RESET};
+use std::fs;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Runs the days of a year against their inputs to find out which parts earned their star,
+/// then regenerates the stars and benchmark tables in the README.
+/// With `dry_run`, the tables are printed instead.
+pub fn progress(global: &Global, timeout: Option<Duration>, dry_run: bool) -> Result<(), Error> {
+    let year = global.year();
+    let answers_path = verify::answers_path(year);
+    let answers = Answers::load(&answers_path).map_err(|message| Error::Parse {
+        path: answers_path.clone(),
+        message,
+    })?;
+    let history_path = history::history_path();
+    let medians = History::load(&history_path)
+        .map_err(|e| Error::io(&history_path, e))?
+        .latest_medians();
+
+    let mut days: Vec<DayProgress> = vec![];
+    for solution in SOLUTIONS.iter().filter(|s| s.year == year) {
+        let source = InputSource::default_for("inputs", year, solution.day);
+        let input = match crate::read_input(&source) {
+            Ok(input) => Some(Arc::<str>::from(input)),
+            Err(e) if e.is_missing() => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut day = DayProgress {
+            day: solution.day,
+            ..DayProgress::default()
+        };
+        for (part, _) in solution.parts() {
+            let expected = answers.get(solution.day, None, part);
+            let verdict = match &input {
+                Some(input) => {
+                    let result = runner::run_isolated(solution, part, input.clone(), timeout);
+                    verify::verify(expected, &result)
+                }
+                None => Verdict::Missing("no input"),
+            };
+            let index = usize::from(part - 1);
+            day.stars[index] = progress::has_star(&verdict, input.is_some(), expected.is_some());
+            day.medians[index] = medians.get(&(year, solution.day, part)).copied();
+            if verdict.is_fail() {
+                println!("{} Part {}: {}", solution.label(), part, verdict);
+            }
+        }
+        days.push(day);
+    }
+
+    let stars = progress::stars_table(year, &days);
+    let bench = progress::bench_table(year, &days);
+    let count: usize = days.iter().map(DayProgress::star_count).sum();
+
+    if dry_run {
+        println!("{}\n{}", stars, bench);
+    } else {
+        let path = progress::readme_path();
+        let readme = fs::read_to_string(&path).map_err(|e| Error::io(&path, e))?;
+        let updated = progress::replace_section(&readme, STARS_MARKER, &stars)
+            .and_then(|readme| progress::replace_section(&readme, BENCH_MARKER, &bench))
+            .map_err(|message| Error::Parse {
+                path: path.clone(),
+                message,
+            })?;
+        fs::write(&path, updated).map_err(|e| Error::io(&path, e))?;
+        println!("Updated the progress tables in \"{}\".", path.display());
+    }
+    println!("⭐ {}{} stars{} in {}.", ANSI_BOLD, count, ANSI_RESET, year);
+    Ok(())
+}
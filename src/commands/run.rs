@@ -0,0 +1,156 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{read_inputs, selected_solutions};
+use crate::bench::{self, BenchResult};
+use crate::cli::{Global, RunArgs};
+use crate::error::Error;
+use crate::history::{self, History};
+use crate::report;
+use crate::runner::{self, PartResult, Status};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Runs the selected solutions, benchmarking them for `bench`.
+pub fn run(global: &Global, args: &RunArgs) -> Result<(), Error> {
+    let human = args.format.is_none();
+
+    let mut results: Vec<PartResult> = vec![];
+    let mut benches: Vec<BenchResult> = vec![];
+
+    for solution in selected_solutions(global, args.day)? {
+        if human {
+            let label = solution.label();
+            println!("{}", "-".repeat(label.len() + 4));
+            println!("{}| {} |{}", ANSI_BOLD, label, ANSI_RESET);
+            println!("{}", "-".repeat(label.len() + 4));
+        }
+
+        let inputs = read_inputs(global, solution)?;
+        if inputs.is_empty() {
+            if human {
+                println!("Not solved.");
+            }
+            results.extend((1..=2).map(|part| PartResult {
+                year: solution.year,
+                day: solution.day,
+                part,
+                input: None,
+                answer: String::new(),
+                elapsed: Duration::ZERO,
+                status: Status::Unsolved,
+                error: None,
+            }));
+        }
+
+        for input in inputs.iter() {
+            let text: Arc<str> = Arc::from(input.text.as_str());
+            for (part, solver) in solution.parts() {
+                // parts are run isolated first, so that only parts that finish in time are benchmarked.
+                let mut result = runner::run_isolated(solution, part, text.clone(), args.timeout);
+                result.input = input.name.clone();
+
+                match &args.bench {
+                    Some(options) if result.is_solved() => {
+                        let mut bench =
+                            bench::bench_part(solution.day, part, solver, &input.text, options);
+                        bench.result.year = solution.year;
+                        bench.result.input = input.name.clone();
+                        if human {
+                            bench::print_bench(&bench);
+                        }
+                        benches.push(bench.clone());
+                        result = bench.result;
+                    }
+                    _ => {
+                        if human {
+                            runner::print_result(&result);
+                        }
+                    }
+                }
+                results.push(result);
+            }
+        }
+    }
+
+    if args.bench.is_some() {
+        record_history(&benches, human);
+    }
+
+    match args.format {
+        Some(format) => print!("{}", report::render(format, &results)),
+        None => println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            runner::total_elapsed(&results).as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        ),
+    }
+
+    let failures = results.iter().filter(|result| result.is_failure()).count();
+    if failures > 0 {
+        return Err(Error::Failed(format!(
+            "{} parts panicked or timed out.",
+            failures
+        )));
+    }
+    Ok(())
+}
+
+fn record_history(benches: &[BenchResult], human: bool) {
+    let commit = history::current_commit();
+    let path = history::history_path();
+    match History::append(&path, &history::entries(&commit, benches)) {
+        Ok(_) => {
+            if human {
+                println!(
+                    "Saved benchmark results for commit {} to \"{}\".",
+                    commit,
+                    path.display()
+                );
+            }
+        }
+        Err(e) => eprintln!("Failed to save benchmark history: {}", e),
+    }
+}
+
+/// Compares the latest benchmark run in the history file against a baseline run.
+/// Fails if any part regressed by more than `threshold` percent.
+pub fn compare(baseline: Option<&str>, threshold: f64) -> Result<(), Error> {
+    let path = history::history_path();
+    let history = History::load(&path).map_err(|e| Error::io(&path, e))?;
+
+    let current = history.latest_commit().ok_or_else(|| {
+        Error::Failed("no benchmarks recorded yet. Run `cargo bench-day <day>` first.".to_string())
+    })?;
+    let baseline = baseline
+        .or_else(|| history.baseline_for(current))
+        .ok_or_else(|| {
+            Error::Failed(format!(
+                "no baseline to compare \"{}\" against. Benchmark another commit first.",
+                current
+            ))
+        })?;
+
+    println!(
+        "Comparing {}{}{} against baseline {}{}{} (threshold: {}%)",
+        ANSI_BOLD, current, ANSI_RESET, ANSI_BOLD, baseline, ANSI_RESET, threshold
+    );
+    let comparisons = history::compare(&history, current, baseline, threshold / 100_f64);
+    if comparisons.is_empty() {
+        println!("No days were benchmarked in both runs.");
+    }
+    for comparison in comparisons.iter() {
+        println!("{}", comparison);
+    }
+
+    let regressed = comparisons.iter().filter(|c| c.regressed).count();
+    if regressed > 0 {
+        return Err(Error::Failed(format!("{} parts regressed.", regressed)));
+    }
+    Ok(())
+}
@@ -0,0 +1,57 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::Global;
+use crate::error::Error;
+use crate::input::{crate_root, year_flag};
+use crate::puzzle::{self, Example};
+use crate::scaffold::{self, Layout, Mode};
+use crate::template::Template;
+use std::fs;
+use std::path::Path;
+
+/// Creates the files of a day from `template`, or only prints what would change with `dry_run`.
+pub fn scaffold(
+    global: &Global,
+    day: u8,
+    template: &str,
+    puzzle: Option<&Path>,
+    mode: Mode,
+    dry_run: bool,
+) -> Result<(), Error> {
+    let root = crate_root();
+    let year = global.year();
+    let layout = Layout::new(root, year, day);
+
+    let example = match puzzle {
+        Some(path) => {
+            let html = fs::read_to_string(path).map_err(|e| Error::io(path, e))?;
+            puzzle::extract_example(&html)
+        }
+        None => Example::default(),
+    };
+
+    let module = Template::load(template)?.render(&scaffold::template_values(&layout, &example))?;
+    let changes = scaffold::plan(&layout, mode, &module, &example)
+        .map_err(|e| Error::Scaffold(format!("failed to scaffold {}: {}", layout.bin(), e)))?;
+
+    for change in changes.iter() {
+        if !dry_run {
+            change.apply().map_err(|e| Error::io(&change.path, e))?;
+        }
+        println!("{}", change.describe(root, dry_run));
+    }
+
+    println!("---");
+    if dry_run {
+        println!("Nothing was written. Run the command without --dry-run to apply these changes.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {}{}` to run your solution.",
+            day,
+            year_flag(year)
+        );
+    }
+    Ok(())
+}
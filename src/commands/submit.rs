@@ -0,0 +1,118 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::cli::Global;
+use crate::client::Client;
+use crate::error::Error;
+use crate::input::{year_flag, InputSource, INPUT_ENV};
+use crate::submit::{self, Outcome, Submission, Submissions};
+use crate::verify::{self, Answers};
+use crate::{runner, solutions};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Adds a correct answer to the answers of `year`, so `cargo verify` checks it from now on.
+fn record_answer(year: u16, day: u8, part: u8, answer: &str) -> Result<(), Error> {
+    let path = verify::answers_path(year);
+    let mut answers = Answers::load(&path).map_err(|message| Error::Parse {
+        path: path.clone(),
+        message,
+    })?;
+    if answers.get(day, None, part).is_some() {
+        return Ok(());
+    }
+    answers.insert(day, None, part, answer.to_string());
+    answers.save(&path).map_err(|e| Error::io(&path, e))?;
+    println!("Recorded the answer in \"{}\".", path.display());
+    Ok(())
+}
+
+/// Runs a part against the input of its day and submits the answer, unless the
+/// submissions so far show it can't be right. Fails unless the answer was correct.
+/// Only the real input's answer can be right, so submit can't be given another input
+/// with `--input` or `AOC_INPUT`.
+pub fn submit(global: &Global, day: u8, part: u8) -> Result<(), Error> {
+    if let Some(source) = &global.input {
+        return Err(Error::Args(format!(
+            "submit always runs against the real input, but got the input \"{}\" from --input or {}.",
+            source, INPUT_ENV
+        )));
+    }
+    let year = global.year();
+    let solution = solutions::get(year, day).ok_or_else(|| {
+        Error::Failed(format!(
+            "{} has no solution yet. Run `cargo scaffold {}{}` first.",
+            solutions::label(year, day),
+            day,
+            year_flag(year)
+        ))
+    })?;
+    let (_, solver) = solution
+        .parts()
+        .into_iter()
+        .find(|(p, _)| *p == part)
+        .ok_or_else(|| Error::Args(format!("part must be 1 or 2, got {}", part)))?;
+
+    let input = crate::read_input(&InputSource::default_for("inputs", year, day))?;
+    let result = runner::run_part(day, part, solver, &input);
+    runner::print_result(&result);
+    if !result.is_solved() {
+        return Err(Error::Failed(
+            "this part is not solved yet, there is nothing to submit.".to_string(),
+        ));
+    }
+    let answer = result.answer;
+    if answer.contains('\n') {
+        // e.g. a drawing that could not be read as letters.
+        return Err(Error::Failed(
+            "not submitting an answer of several lines, submit it by hand.".to_string(),
+        ));
+    }
+
+    let path = submit::submissions_path();
+    let submissions = Submissions::load(&path).map_err(|e| Error::io(&path, e))?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    if let Err(refusal) = submissions.check(year, day, part, &answer, now) {
+        return Err(Error::Failed(format!(
+            "not submitting \"{}\": {}",
+            answer, refusal
+        )));
+    }
+
+    let client = Client::from_env()?;
+    println!("---");
+    println!(
+        "Submitting \"{}\" for day {} part {}, {}...",
+        answer, day, part, year
+    );
+    let response = client.submit(year, day, part, &answer)?;
+
+    println!("{}", response.outcome);
+    if let Some(wait) = response.wait {
+        println!("Wait {}s before submitting again.", wait.as_secs());
+    }
+
+    let submission = Submission {
+        timestamp: now,
+        year,
+        day,
+        part,
+        outcome: response.outcome,
+        wait_until: response.wait.map(|wait| now + wait.as_secs()),
+        answer: answer.clone(),
+    };
+    if let Err(e) = Submissions::append(&path, &submission) {
+        eprintln!("Failed to record the submission: {}", e);
+    }
+
+    match response.outcome {
+        Outcome::Correct => {
+            println!("🎄 Day {} part {} solved!", day, part);
+            record_answer(year, day, part, &answer)
+        }
+        _ => Err(Error::Failed("the answer was not accepted.".to_string())),
+    }
+}
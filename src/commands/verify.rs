@@ -0,0 +1,109 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{read_inputs, selected_solutions};
+use crate::cli::Global;
+use crate::error::Error;
+use crate::input::INPUT_ENV;
+use crate::runner;
+use crate::verify::{self, Answers, Verdict};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Runs every day against its real input and checks the answers against the answers file of its year.
+/// With `record`, answers of parts without a known answer are added to the file.
+/// Fails if any part failed. The known answers are those of the real inputs, so verify
+/// can't be given another input with `--input` or `AOC_INPUT`.
+pub fn verify(
+    global: &Global,
+    day: Option<u8>,
+    record: bool,
+    timeout: Option<Duration>,
+) -> Result<(), Error> {
+    if let Some(source) = &global.input {
+        return Err(Error::Args(format!(
+            "verify always runs against the real inputs, but got the input \"{}\" from --input or {}.",
+            source, INPUT_ENV
+        )));
+    }
+    let solutions = selected_solutions(global, day)?;
+    let years: BTreeSet<u16> = solutions.iter().map(|s| s.year).collect();
+    let mut failed = 0;
+
+    for year in years {
+        let path = verify::answers_path(year);
+        let mut answers = Answers::load(&path).map_err(|message| Error::Parse {
+            path: path.clone(),
+            message,
+        })?;
+        let mut recorded = 0;
+
+        for solution in solutions.iter().filter(|s| s.year == year) {
+            let inputs = read_inputs(global, solution)?;
+            if inputs.is_empty() {
+                for (part, _) in solution.parts() {
+                    println!(
+                        "{} Part {}: {}",
+                        solution.label(),
+                        part,
+                        Verdict::Missing("no input")
+                    );
+                }
+            }
+
+            for input in inputs.iter() {
+                let name = input.name.as_deref();
+                let label = match name {
+                    Some(name) => format!("{} ({})", solution.label(), name),
+                    None => solution.label(),
+                };
+                let text: Arc<str> = Arc::from(input.text.as_str());
+                for (part, _) in solution.parts() {
+                    let result = runner::run_isolated(solution, part, text.clone(), timeout);
+                    if let Some(error) = &result.error {
+                        eprintln!(
+                            "{} Part {} {}: {}",
+                            label,
+                            part,
+                            result.status.as_str(),
+                            error
+                        );
+                    }
+                    let verdict = verify::verify(answers.get(solution.day, name, part), &result);
+                    // answers are stored one per line, so e.g. a drawn screen can't be recorded.
+                    if record
+                        && matches!(verdict, Verdict::Missing(_))
+                        && result.is_solved()
+                        && !result.answer.contains('\n')
+                    {
+                        answers.insert(solution.day, name, part, result.answer);
+                        recorded += 1;
+                    }
+                    println!("{} Part {}: {}", label, part, verdict);
+                    if verdict.is_fail() {
+                        failed += 1;
+                    }
+                }
+            }
+        }
+
+        if recorded > 0 {
+            answers.save(&path).map_err(|e| Error::io(&path, e))?;
+            println!(
+                "Recorded {} new answers in \"{}\".",
+                recorded,
+                path.display()
+            );
+        }
+    }
+
+    if failed > 0 {
+        return Err(Error::Failed(format!(
+            "{} parts failed verification.",
+            failed
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,92 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::ClientError;
+use crate::input::InputError;
+use crate::template::TemplateError;
+use std::fmt::{self, Display};
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can make a command of the `aoc` CLI fail.
+#[derive(Debug)]
+pub enum Error {
+    /// Invalid command line arguments.
+    Args(String),
+    Input(InputError),
+    Client(ClientError),
+    Template(TemplateError),
+    Scaffold(String),
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+    /// A file that exists but could not be parsed, e.g. `src/answers.txt`.
+    Parse {
+        path: PathBuf,
+        message: String,
+    },
+    /// The command ran, but what it checked did not pass, e.g. a wrong answer in `verify`.
+    Failed(String),
+}
+
+impl Error {
+    pub fn io(path: impl Into<PathBuf>, error: io::Error) -> Self {
+        Error::Io {
+            path: path.into(),
+            error,
+        }
+    }
+
+    /// The exit code of the process: `2` for usage errors and `1` for everything else.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Args(_) => 2,
+            _ => 1,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Args(message) => write!(f, "{}", message),
+            Error::Input(e) => write!(f, "{}", e),
+            Error::Client(e) => write!(f, "{}", e),
+            Error::Template(e) => write!(f, "{}", e),
+            Error::Scaffold(message) => write!(f, "{}", message),
+            Error::Io { path, error } => write!(f, "\"{}\": {}", path.display(), error),
+            Error::Parse { path, message } => {
+                write!(f, "could not read \"{}\": {}", path.display(), message)
+            }
+            Error::Failed(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<pico_args::Error> for Error {
+    fn from(e: pico_args::Error) -> Self {
+        Error::Args(e.to_string())
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(e: InputError) -> Self {
+        Error::Input(e)
+    }
+}
+
+impl From<ClientError> for Error {
+    fn from(e: ClientError) -> Self {
+        Error::Client(e)
+    }
+}
+
+impl From<TemplateError> for Error {
+    fn from(e: TemplateError) -> Self {
+        Error::Template(e)
+    }
+}
@@ -2,3 +2,14 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod cycle;
+pub mod direction;
+pub mod grid;
+pub mod interval_set;
+pub mod point;
+pub mod search;
+
+pub use direction::{Direction, Direction8};
+pub use grid::Grid;
+pub use interval_set::IntervalSet;
+pub use point::{Point2, Point3};
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The value of `metric` after `n` steps of a simulation that eventually runs in a cycle,
+/// without simulating all `n` steps, e.g. the height of a tower after 10^12 rocks.
+///
+/// `step` advances `state` by one step. `key` must only return the same key for two states
+/// that evolve the same way from there on, so that `metric` grows by the same amount in every
+/// cycle. If `n` is reached before a state repeats, the simulated value is returned as is.
+pub fn extrapolate<S, K>(
+    state: &mut S,
+    n: u64,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+) -> i64
+where
+    K: Eq + Hash,
+{
+    // `values[i]` is the metric after `i` steps.
+    let mut values = vec![metric(state)];
+    let mut seen = HashMap::new();
+    for i in 0.. {
+        if i == n {
+            return values[i as usize];
+        }
+        if let Some(start) = seen.insert(key(state), i) {
+            let cycle = i - start;
+            let growth = values[i as usize] - values[start as usize];
+            let (cycles, rest) = ((n - start) / cycle, (n - start) % cycle);
+            return values[(start + rest) as usize] + cycles as i64 * growth;
+        }
+        step(state);
+        values.push(metric(state));
+    }
+    unreachable!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extrapolate() {
+        // a counter that runs 0, 1, 2, 3, 4, 2, 3, 4, 2, ... and a total of its values.
+        let step = |(counter, total): &mut (i64, i64)| {
+            *counter = if *counter == 4 { 2 } else { *counter + 1 };
+            *total += *counter;
+        };
+        let simulate = |n: u64| {
+            let mut state = (0, 0);
+            for _ in 0..n {
+                step(&mut state);
+            }
+            state.1
+        };
+        for n in [0, 1, 2, 5, 6, 7, 100, 101, 102] {
+            let mut state = (0, 0);
+            let total = extrapolate(&mut state, n, step, |&(counter, _)| counter, |s| s.1);
+            assert_eq!(total, simulate(n), "after {} steps", n);
+        }
+        let mut state = (0, 0);
+        let total = extrapolate(&mut state, 1_000_000_000_000, step, |s| s.0, |s| s.1);
+        assert_eq!(total, 1 + 2 + 3 + 4 + (1_000_000_000_000 - 4) / 3 * 9);
+    }
+}
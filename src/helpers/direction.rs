@@ -0,0 +1,153 @@
+use super::Point2;
+
+/// One of the four directions on a grid, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+/// One of the eight directions on a grid including the diagonals, in clockwise order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    /// The direction after a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// A single step in this direction, with up towards negative `y`.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::Down => Point2::new(0, 1),
+            Self::Left => Point2::new(-1, 0),
+        }
+    }
+}
+
+/// Parses the arrows `^>v<`, the letters `URDL` and the compass points `NESW`.
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, String> {
+        match c {
+            '^' | 'U' | 'N' => Ok(Self::Up),
+            '>' | 'R' | 'E' => Ok(Self::Right),
+            'v' | 'D' | 'S' => Ok(Self::Down),
+            '<' | 'L' | 'W' => Ok(Self::Left),
+            _ => Err(format!("invalid direction: {:?}", c)),
+        }
+    }
+}
+
+impl Direction8 {
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    /// The direction after an eighth turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// The direction after an eighth turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// A single step in this direction, with up towards negative `y`.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Self::Up => Point2::new(0, -1),
+            Self::UpRight => Point2::new(1, -1),
+            Self::Right => Point2::new(1, 0),
+            Self::DownRight => Point2::new(1, 1),
+            Self::Down => Point2::new(0, 1),
+            Self::DownLeft => Point2::new(-1, 1),
+            Self::Left => Point2::new(-1, 0),
+            Self::UpLeft => Point2::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        Self::ALL[direction as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turn() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+    }
+
+    #[test]
+    fn test_offset() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.offset().rotate_right(),
+                direction.turn_right().offset()
+            );
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+            assert_eq!(Direction8::from(direction).offset(), direction.offset());
+        }
+        for direction in Direction8::ALL {
+            assert_eq!(-direction.offset(), direction.opposite().offset());
+            assert_eq!(direction.offset().chebyshev(Point2::ORIGIN), 1);
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('R'), Ok(Direction::Right));
+        assert_eq!(Direction::try_from('S'), Ok(Direction::Down));
+        assert!(Direction::try_from('x').is_err());
+    }
+}
@@ -0,0 +1,323 @@
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid as `(row, column)`, with `(0, 0)` at the top left.
+pub type Position = (usize, usize);
+
+/// Steps to the neighbours of a position: up, right, down and left.
+pub const NEIGHBOURS: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+/// Steps to the neighbours of a position including the diagonals, clockwise from up.
+pub const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// A rectangular grid, stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A grid of `width` columns from its cells, row by row.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells don't fill rows of width {}",
+            cells.len(),
+            width
+        );
+        Self {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Panics if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "all rows of a grid must have the same length"
+        );
+        Self {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses a grid from puzzle text with one row per line, turning every character into a cell.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        Self::from_rows(
+            input
+                .lines()
+                .map(|line| line.chars().map(&mut cell).collect())
+                .collect(),
+        )
+    }
+
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Position) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| (row, column)))
+            .map(&mut cell)
+            .collect();
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The position of a signed `(row, column)`, or `None` if it is outside the grid.
+    pub fn checked(&self, (row, column): (isize, isize)) -> Option<Position> {
+        let row = usize::try_from(row).ok()?;
+        let column = usize::try_from(column).ok()?;
+        (row < self.height && column < self.width).then_some((row, column))
+    }
+
+    /// Wraps a signed `(row, column)` around the edges of the grid.
+    pub fn wrap(&self, (row, column): (isize, isize)) -> Position {
+        (
+            row.rem_euclid(self.height as isize) as usize,
+            column.rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    /// The position `(dr, dc)` away from `pos`, or `None` if that is outside the grid.
+    pub fn offset(&self, (row, column): Position, (dr, dc): (isize, isize)) -> Option<Position> {
+        self.checked((row as isize + dr, column as isize + dc))
+    }
+
+    pub fn get(&self, (row, column): Position) -> Option<&T> {
+        (row < self.height && column < self.width).then(|| &self.cells[row * self.width + column])
+    }
+
+    pub fn get_mut(&mut self, (row, column): Position) -> Option<&mut T> {
+        (row < self.height && column < self.width)
+            .then(|| &mut self.cells[row * self.width + column])
+    }
+
+    /// The neighbours above, right, below and left of `pos` that are inside the grid.
+    pub fn neighbours(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The neighbours of `pos` including the diagonals that are inside the grid.
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        NEIGHBOURS8
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The positions from `pos` (exclusive) in the direction of `step` up to the edge of the grid.
+    pub fn ray(&self, pos: Position, step: (isize, isize)) -> impl Iterator<Item = Position> + '_ {
+        let mut current = Some(pos);
+        std::iter::from_fn(move || {
+            current = self.offset(current?, step);
+            current
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(column).step_by(self.width.max(1))
+    }
+
+    /// All positions, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| (row, column)))
+    }
+
+    /// All cells with their positions, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// The grid mirrored along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.height, self.width, |(row, column)| {
+            self[(column, row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, column): Position) -> &T {
+        assert!(
+            row < self.height && column < self.width,
+            "({}, {}) is outside of the {}x{} grid",
+            row,
+            column,
+            self.height,
+            self.width
+        );
+        &self.cells[row * self.width + column]
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, (row, column): Position) -> &mut T {
+        assert!(
+            row < self.height && column < self.width,
+            "({}, {}) is outside of the {}x{} grid",
+            row,
+            column,
+            self.height,
+            self.width
+        );
+        &mut self.cells[row * self.width + column]
+    }
+}
+
+/// Prints one line per row, e.g. to compare a grid with the puzzle description.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef\n", |c| c)
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.position(|c| *c == 'f'), Some((1, 2)));
+        assert_eq!(grid.to_string(), "abc\ndef");
+        assert_eq!(
+            Grid::parse("12\n34", |c| c.to_digit(10).unwrap())[(1, 1)],
+            4
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "same length")]
+    fn test_parse_ragged() {
+        Grid::parse("abc\nde", |c| c);
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = grid();
+        assert_eq!(grid.checked((1, 2)), Some((1, 2)));
+        assert_eq!(grid.checked((-1, 0)), None);
+        assert_eq!(grid.checked((0, 3)), None);
+        assert_eq!(grid.wrap((-1, 4)), (1, 1));
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(grid.offset((0, 0), (0, -1)), None);
+        *grid.get_mut((0, 0)).unwrap() = 'x';
+        grid[(1, 2)] = 'y';
+        assert_eq!(grid.to_string(), "xbc\ndey");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        assert_eq!(
+            grid.neighbours((0, 1)).collect::<Vec<_>>(),
+            vec![(0, 2), (1, 1), (0, 0)]
+        );
+        assert_eq!(grid.neighbours8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = grid();
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.column(2).collect::<String>(), "cf");
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.ray((0, 0), (0, 1)).collect::<Vec<_>>(),
+            vec![(0, 1), (0, 2)]
+        );
+        assert_eq!(grid.ray((1, 1), (1, 0)).count(), 0);
+        assert_eq!(grid.iter().map(|(_, c)| *c).collect::<String>(), "abcdef");
+    }
+
+    #[test]
+    fn test_transpose_and_map() {
+        let grid = grid();
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.transpose(), grid);
+        assert_eq!(grid.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+        assert_eq!(Grid::filled(2, 1, 0).to_string(), "00");
+        assert_eq!(
+            Grid::from_fn(2, 2, |(r, c)| r * 2 + c).to_string(),
+            "01\n23"
+        );
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3, 4])[(1, 0)], 3);
+    }
+}
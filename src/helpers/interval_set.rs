@@ -0,0 +1,241 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers stored as sorted, disjoint and non-adjacent inclusive intervals, so that
+/// e.g. the coverage of a row on day 15 is a handful of intervals instead of millions of values.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<(i64, i64)>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds all values of `range`, merging it with the intervals it overlaps or touches.
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .intervals
+            .partition_point(|&(_, e)| e < start.saturating_sub(1));
+        let last = self
+            .intervals
+            .partition_point(|&(s, _)| s <= end.saturating_add(1));
+        if first < last {
+            start = start.min(self.intervals[first].0);
+            end = end.max(self.intervals[last - 1].1);
+        }
+        self.intervals.splice(first..last, [(start, end)]);
+    }
+
+    /// Removes all values of `range`, splitting the interval it falls in if needed.
+    pub fn remove(&mut self, range: RangeInclusive<i64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.intervals.partition_point(|&(_, e)| e < start);
+        let last = self.intervals.partition_point(|&(s, _)| s <= end);
+        let mut kept = Vec::new();
+        if first < last {
+            let (first_start, _) = self.intervals[first];
+            if first_start < start {
+                kept.push((first_start, start - 1));
+            }
+            let (_, last_end) = self.intervals[last - 1];
+            if last_end > end {
+                kept.push((end + 1, last_end));
+            }
+        }
+        self.intervals.splice(first..last, kept);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.iter() {
+            union.insert(range);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (a, b) = (&self.intervals, &other.intervals);
+        let (mut i, mut j) = (0, 0);
+        let mut intervals = Vec::new();
+        while i < a.len() && j < b.len() {
+            let (start, end) = (a[i].0.max(b[j].0), a[i].1.min(b[j].1));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a[i].1 < b[j].1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values of this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals
+            .iter()
+            .map(|&(start, end)| start.abs_diff(end) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.contains_range(value..=value)
+    }
+
+    /// Whether all values of `range` are in the set. An empty range is always contained.
+    pub fn contains_range(&self, range: RangeInclusive<i64>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return true;
+        }
+        let i = self.intervals.partition_point(|&(_, e)| e < start);
+        self.intervals
+            .get(i)
+            .is_some_and(|&(s, e)| s <= start && end <= e)
+    }
+
+    /// Whether any value of `range` is in the set. An empty range never overlaps.
+    pub fn overlaps(&self, range: RangeInclusive<i64>) -> bool {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return false;
+        }
+        let i = self.intervals.partition_point(|&(_, e)| e < start);
+        self.intervals.get(i).is_some_and(|&(s, _)| s <= end)
+    }
+
+    /// The intervals in the set, from lowest to highest.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<i64>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+
+    /// The intervals within `bounds` that are not in the set, from lowest to highest.
+    pub fn gaps(&self, bounds: RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+        let (mut next, end) = bounds.into_inner();
+        let mut gaps = Vec::new();
+        for &(s, e) in &self.intervals {
+            if next > end || s > end {
+                break;
+            }
+            if s > next {
+                gaps.push(next..=s - 1);
+            }
+            next = next.max(e.saturating_add(1));
+        }
+        if next <= end {
+            gaps.push(next..=end);
+        }
+        gaps
+    }
+}
+
+impl From<RangeInclusive<i64>> for IntervalSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet) -> Vec<RangeInclusive<i64>> {
+        set.iter().collect()
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut set = IntervalSet::from_iter([10..=12, 1..=3, 20..=25]);
+        assert_eq!(ranges(&set), vec![1..=3, 10..=12, 20..=25]);
+        // touching intervals are merged too.
+        set.insert(4..=5);
+        assert_eq!(ranges(&set), vec![1..=5, 10..=12, 20..=25]);
+        set.insert(11..=21);
+        assert_eq!(ranges(&set), vec![1..=5, 10..=25]);
+        // empty ranges are ignored.
+        let (start, end) = (7, 6);
+        set.insert(start..=end);
+        assert_eq!(set.len(), 5 + 16);
+        assert!(IntervalSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = IntervalSet::from_iter([1..=10, 20..=30]);
+        set.remove(5..=6);
+        assert_eq!(ranges(&set), vec![1..=4, 7..=10, 20..=30]);
+        set.remove(9..=25);
+        assert_eq!(ranges(&set), vec![1..=4, 7..=8, 26..=30]);
+        set.remove(0..=100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = IntervalSet::from_iter([1..=10, 20..=30]);
+        let b = IntervalSet::from_iter([5..=22, 29..=40]);
+        assert_eq!(ranges(&a.union(&b)), vec![1..=40]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=22, 29..=30]);
+        assert_eq!(ranges(&a.difference(&b)), vec![1..=4, 23..=28]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19, 31..=40]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = IntervalSet::from_iter([1..=10, 20..=30]);
+        assert!(set.contains(1) && set.contains(25));
+        assert!(!set.contains(0) && !set.contains(15));
+        assert!(set.contains_range(2..=8));
+        assert!(!set.contains_range(5..=25));
+        assert!(set.overlaps(5..=25));
+        assert!(set.overlaps(30..=40));
+        assert!(!set.overlaps(11..=19));
+        // empty ranges are contained in but don't overlap any set.
+        let (start, end) = (5, 3);
+        assert!(set.contains_range(start..=end));
+        assert!(!set.overlaps(start..=end));
+    }
+
+    #[test]
+    fn test_gaps() {
+        let set = IntervalSet::from_iter([1..=10, 20..=30]);
+        assert_eq!(set.gaps(0..=40), vec![0..=0, 11..=19, 31..=40]);
+        assert_eq!(set.gaps(5..=25), vec![11..=19]);
+        assert_eq!(set.gaps(2..=8), vec![]);
+        assert_eq!(IntervalSet::new().gaps(1..=3), vec![1..=3]);
+    }
+}
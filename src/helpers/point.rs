@@ -0,0 +1,216 @@
+use super::grid::Position;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A point on a plane. `y` grows downwards, like the rows of a [`Grid`](super::Grid), so
+/// turning right turns clockwise on screen.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+/// A point in space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+/// Component-wise `+`, `-`, `+=`, `-=` and negation, and scaling by an `i64`.
+macro_rules! impl_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = Self;
+
+            fn mul(self, factor: i64) -> Self {
+                Self { $($field: self.$field * factor),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl $point {
+            /// The sum of the distances along every axis.
+            pub fn manhattan(self, other: Self) -> i64 {
+                0 $(+ (self.$field - other.$field).abs())+
+            }
+
+            /// The largest distance along any axis, i.e. the number of king moves between points.
+            pub fn chebyshev(self, other: Self) -> i64 {
+                0 $(.max((self.$field - other.$field).abs()))+
+            }
+
+            /// The point with every coordinate replaced by its sign: a single step towards it.
+            pub fn signum(self) -> Self {
+                Self { $($field: self.$field.signum()),+ }
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+impl Point2 {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Rotates a quarter turn clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates a quarter turn counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    /// The points above, right, below and left of this point.
+    pub fn neighbours(self) -> [Self; 4] {
+        super::Direction::ALL.map(|direction| self + direction.offset())
+    }
+
+    /// The points around this point including the diagonals, clockwise from up.
+    pub fn neighbours8(self) -> [Self; 8] {
+        super::Direction8::ALL.map(|direction| self + direction.offset())
+    }
+
+    /// The grid position of this point, or `None` if it has a negative coordinate.
+    pub fn position(self) -> Option<Position> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+/// The point of a grid position: its column is `x` and its row is `y`.
+impl From<Position> for Point2 {
+    fn from((row, column): Position) -> Self {
+        Self::new(column as i64, row as i64)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    /// The six points that share a face with this point.
+    pub fn neighbours(self) -> [Self; 6] {
+        [
+            Self::new(-1, 0, 0),
+            Self::new(1, 0, 0),
+            Self::new(0, -1, 0),
+            Self::new(0, 1, 0),
+            Self::new(0, 0, -1),
+            Self::new(0, 0, 1),
+        ]
+        .map(|step| self + step)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ops() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a + b, Point2::new(5, 0));
+        assert_eq!(a - b, Point2::new(-3, 4));
+        assert_eq!(-a * 3, Point2::new(-3, -6));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+        assert_eq!(Point3::new(1, 2, 3) - Point3::ORIGIN, Point3::new(1, 2, 3));
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!((b - a).signum(), Point2::new(1, -1));
+        assert_eq!(Point3::new(1, 1, 1).manhattan(Point3::new(2, 1, 0)), 2);
+        assert_eq!(Point3::new(1, 1, 1).chebyshev(Point3::new(2, 1, -3)), 4);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let up = Point2::new(0, -1);
+        assert_eq!(up.rotate_right(), Point2::new(1, 0));
+        assert_eq!(up.rotate_left(), Point2::new(-1, 0));
+        assert_eq!(
+            Point2::new(2, 3).rotate_left().rotate_right(),
+            Point2::new(2, 3)
+        );
+        assert_eq!(up.rotate_right().rotate_right(), -up);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let p = Point2::new(1, 1);
+        assert_eq!(
+            p.neighbours(),
+            [
+                Point2::new(1, 0),
+                Point2::new(2, 1),
+                Point2::new(1, 2),
+                Point2::new(0, 1)
+            ]
+        );
+        assert!(p.neighbours8().iter().all(|n| n.chebyshev(p) == 1));
+        assert!(Point3::ORIGIN
+            .neighbours()
+            .iter()
+            .all(|n| n.manhattan(Point3::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_position() {
+        assert_eq!(Point2::from((2, 3)), Point2::new(3, 2));
+        assert_eq!(Point2::new(3, 2).position(), Some((2, 3)));
+        assert_eq!(Point2::new(-1, 2).position(), None);
+    }
+}
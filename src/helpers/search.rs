@@ -0,0 +1,244 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The path from a start to the first node that reaches `goal`, with the fewest steps.
+///
+/// The search starts from all `starts` at once, so the path begins at whichever start is
+/// closest to a goal. Each node is expanded at most once.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return Some(path(node, |node| parents[node].clone()));
+        }
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The cheapest path from a start to a node that reaches `goal` and its cost, where
+/// `neighbours` yields the next nodes with the cost of the step to them.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    astar(starts, neighbours, |_| 0, goal)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` that estimates the remaining cost to a
+/// goal. The path is only the cheapest if the heuristic never overestimates.
+pub fn astar<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    // the heap refers to nodes by their index in `queued`, so nodes don't need to be `Ord`.
+    let mut queued = Vec::new();
+    let mut heap = BinaryHeap::new();
+    let mut best: HashMap<N, (u64, Option<N>)> = HashMap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert((0, None));
+            heap.push(Reverse((heuristic(&start), 0, queued.len())));
+            queued.push(start);
+        }
+    }
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = queued[index].clone();
+        if cost > best[&node].0 {
+            // a cheaper way to this node was queued after this one.
+            continue;
+        }
+        if goal(&node) {
+            return Some((path(node, |node| best[node].1.clone()), cost));
+        }
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            if best
+                .get(&next)
+                .is_some_and(|&(known, _)| known <= next_cost)
+            {
+                continue;
+            }
+            best.insert(next.clone(), (next_cost, Some(node.clone())));
+            heap.push(Reverse((
+                next_cost + heuristic(&next),
+                next_cost,
+                queued.len(),
+            )));
+            queued.push(next);
+        }
+    }
+    None
+}
+
+/// The shortest distances between all pairs of `nodes`, keyed by `(from, to)`. Pairs without
+/// a path between them are missing, and neighbours that are not in `nodes` are ignored.
+pub fn floyd_warshall<N, I>(
+    nodes: &[N],
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<(N, N), u64>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let n = nodes.len();
+    let indices: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    let mut distances = vec![vec![None; n]; n];
+    for (from, node) in nodes.iter().enumerate() {
+        distances[from][from] = Some(0);
+        for (next, cost) in neighbours(node) {
+            if let Some(&to) = indices.get(&next) {
+                if distances[from][to].is_none_or(|known| cost < known) {
+                    distances[from][to] = Some(cost);
+                }
+            }
+        }
+    }
+    for via in 0..n {
+        let from_via = distances[via].clone();
+        for row in distances.iter_mut() {
+            let Some(first) = row[via] else {
+                continue;
+            };
+            for (known, second) in row.iter_mut().zip(&from_via) {
+                if let Some(second) = second {
+                    let through = first + second;
+                    if known.is_none_or(|known| through < known) {
+                        *known = Some(through);
+                    }
+                }
+            }
+        }
+    }
+
+    let mut pairs = HashMap::new();
+    for (from, row) in distances.into_iter().enumerate() {
+        for (to, distance) in row.into_iter().enumerate() {
+            if let Some(distance) = distance {
+                pairs.insert((nodes[from].clone(), nodes[to].clone()), distance);
+            }
+        }
+    }
+    pairs
+}
+
+/// Follows the parents of `end` back to a start and returns the nodes from there to `end`.
+fn path<N>(end: N, mut parent: impl FnMut(&N) -> Option<N>) -> Vec<N> {
+    let mut nodes = vec![end];
+    while let Some(node) = parent(nodes.last().unwrap()) {
+        nodes.push(node);
+    }
+    nodes.reverse();
+    nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A weighted graph: a -1- b -1- c -1- d, with a shortcut a -5- d and a lone e.
+    fn edges(node: &char) -> Vec<(char, u64)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('a', 1), ('c', 1)],
+            'c' => vec![('b', 1), ('d', 1)],
+            'd' => vec![('c', 1), ('a', 5)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(node: &char) -> Vec<char> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        assert_eq!(bfs(['a'], unweighted, |&n| n == 'd'), Some(vec!['a', 'd']));
+        assert_eq!(
+            bfs(['a'], unweighted, |&n| n == 'c'),
+            Some(vec!['a', 'b', 'c'])
+        );
+        assert_eq!(bfs(['a'], unweighted, |&n| n == 'a'), Some(vec!['a']));
+        assert_eq!(bfs(['a'], unweighted, |&n| n == 'e'), None);
+        // the path starts at the closest of several starts.
+        assert_eq!(
+            bfs(['a', 'c', 'e'], unweighted, |&n| n == 'c'),
+            Some(vec!['c'])
+        );
+        assert_eq!(
+            bfs(['e', 'a'], unweighted, |&n| n == 'c'),
+            Some(vec!['a', 'b', 'c'])
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        assert_eq!(
+            dijkstra(['a'], edges, |&n| n == 'd'),
+            Some((vec!['a', 'b', 'c', 'd'], 3))
+        );
+        assert_eq!(dijkstra(['e'], edges, |&n| n == 'a'), None);
+        assert_eq!(
+            dijkstra(['e', 'd'], edges, |&n| n == 'b'),
+            Some((vec!['d', 'c', 'b'], 2))
+        );
+    }
+
+    #[test]
+    fn test_astar() {
+        // a walk on a line towards 10, with the distance as the heuristic.
+        let walk = |&n: &i64| [(n - 1, 1), (n + 1, 1)];
+        let (path, cost) = astar([0], walk, |&n| n.abs_diff(10), |&n| n == 10).unwrap();
+        assert_eq!(cost, 10);
+        assert_eq!(path, (0..=10).collect::<Vec<_>>());
+        assert_eq!(
+            astar(['a'], edges, |_| 0, |&n| n == 'd'),
+            dijkstra(['a'], edges, |&n| n == 'd')
+        );
+    }
+
+    #[test]
+    fn test_floyd_warshall() {
+        let distances = floyd_warshall(&['a', 'b', 'c', 'd', 'e'], edges);
+        assert_eq!(distances[&('a', 'd')], 3);
+        assert_eq!(distances[&('d', 'a')], 3);
+        assert_eq!(distances[&('b', 'b')], 0);
+        assert_eq!(distances.get(&('a', 'e')), None);
+        assert_eq!(distances.len(), 4 * 4 + 1);
+        // nodes outside of the list are skipped.
+        assert_eq!(floyd_warshall(&['a', 'd'], edges)[&('a', 'd')], 5);
+    }
+}
@@ -0,0 +1,318 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::{BenchResult, Stats};
+use crate::solutions::label;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const HEADER: &str = "commit,timestamp,year,day,part,iterations,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// One benchmarked part, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub commit: String,
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub stats: Stats,
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{},{},{}",
+            self.commit,
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.stats.iterations,
+            self.stats.min.as_nanos(),
+            self.stats.median.as_nanos(),
+            self.stats.mean.as_nanos(),
+            self.stats.stddev.as_nanos()
+        )
+    }
+
+    /// Parses a line of the history file, `None` if it is malformed.
+    fn from_line(line: &str) -> Option<Self> {
+        let fields: Vec<&str> = line.split(',').collect();
+        if fields.len() != 10 {
+            return None;
+        }
+        let nanos = |s: &str| s.parse::<u64>().ok().map(Duration::from_nanos);
+        Some(Self {
+            commit: fields[0].to_string(),
+            timestamp: fields[1].parse().ok()?,
+            year: fields[2].parse().ok()?,
+            day: fields[3].parse().ok()?,
+            part: fields[4].parse().ok()?,
+            stats: Stats {
+                iterations: fields[5].parse().ok()?,
+                min: nanos(fields[6])?,
+                median: nanos(fields[7])?,
+                mean: nanos(fields[8])?,
+                stddev: nanos(fields[9])?,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct History {
+    /// Entries in the order they were recorded.
+    pub entries: Vec<Entry>,
+}
+
+impl History {
+    pub fn parse(contents: &str) -> Self {
+        Self {
+            entries: contents
+                .lines()
+                .filter(|line| !line.starts_with("commit,"))
+                .filter_map(Entry::from_line)
+                .collect(),
+        }
+    }
+
+    /// Loads the history file, treating a missing file as an empty history.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends entries to the history file, creating it if needed.
+    pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if is_new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        for entry in entries {
+            writeln!(file, "{}", entry.to_line())?;
+        }
+        Ok(())
+    }
+
+    /// The commit of the most recently recorded entry.
+    pub fn latest_commit(&self) -> Option<&str> {
+        self.entries.last().map(|entry| entry.commit.as_str())
+    }
+
+    /// The most recently recorded commit other than `commit`.
+    pub fn baseline_for(&self, commit: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|entry| entry.commit.as_str())
+            .find(|c| *c != commit)
+    }
+
+    /// The latest median timing per `(year, day, part)` recorded for `commit`.
+    pub fn medians(&self, commit: &str) -> HashMap<(u16, u8, u8), Duration> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.commit == commit)
+            .map(|entry| ((entry.year, entry.day, entry.part), entry.stats.median))
+            .collect()
+    }
+
+    /// The median timing per `(year, day, part)` of the most recent benchmark of each part,
+    /// whichever commit it was recorded for.
+    pub fn latest_medians(&self) -> HashMap<(u16, u8, u8), Duration> {
+        self.entries
+            .iter()
+            .map(|entry| ((entry.year, entry.day, entry.part), entry.stats.median))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change of the median, e.g. `0.25` for 25% slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} Part {}: {:.2?} -> {:.2?} ({:+.1}%){}",
+            label(self.year, self.day),
+            self.part,
+            self.baseline,
+            self.current,
+            self.change * 100_f64,
+            if self.regressed { " REGRESSED" } else { "" }
+        )
+    }
+}
+
+/// Compares the medians of every part recorded for both commits.
+/// A part regressed if its median grew by more than `threshold` (e.g. `0.1` for 10%).
+pub fn compare(
+    history: &History,
+    current: &str,
+    baseline: &str,
+    threshold: f64,
+) -> Vec<Comparison> {
+    let before = history.medians(baseline);
+    let mut comparisons: Vec<Comparison> = history
+        .medians(current)
+        .into_iter()
+        .filter_map(|((year, day, part), current)| {
+            let baseline = *before.get(&(year, day, part))?;
+            let change = if baseline.is_zero() {
+                0_f64
+            } else {
+                current.as_secs_f64() / baseline.as_secs_f64() - 1_f64
+            };
+            Some(Comparison {
+                year,
+                day,
+                part,
+                baseline,
+                current,
+                change,
+                regressed: change > threshold,
+            })
+        })
+        .collect();
+    comparisons.sort_by_key(|c| (c.year, c.day, c.part));
+    comparisons
+}
+
+pub fn history_path() -> PathBuf {
+    crate::input::crate_root().join("bench_history.csv")
+}
+
+/// The short hash of `HEAD`, suffixed with `-dirty` if the working tree has changes.
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(crate::input::crate_root())
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{}-dirty", hash),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+/// Turns benchmark results into history entries for `commit`, skipping unsolved parts.
+/// Only the default input of a day is tracked, so runs stay comparable.
+pub fn entries(commit: &str, results: &[BenchResult]) -> Vec<Entry> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    results
+        .iter()
+        .filter(|bench| bench.result.input.is_none())
+        .filter_map(|bench| {
+            Some(Entry {
+                commit: commit.to_string(),
+                timestamp,
+                year: bench.result.year,
+                day: bench.result.day,
+                part: bench.result.part,
+                stats: bench.stats?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DEFAULT_YEAR;
+
+    fn entry(commit: &str, day: u8, part: u8, median_us: u64) -> Entry {
+        let median = Duration::from_micros(median_us);
+        Entry {
+            commit: commit.to_string(),
+            timestamp: 0,
+            year: DEFAULT_YEAR,
+            day,
+            part,
+            stats: Stats {
+                iterations: 10,
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let e = entry("abc1234", 17, 2, 1500);
+        let contents = format!("{}\n{}\n", HEADER, e.to_line());
+        assert_eq!(History::parse(&contents).entries, vec![e]);
+    }
+
+    #[test]
+    fn test_parse_invalid_lines() {
+        let e = entry("abc1234", 17, 2, 1500);
+        let contents = format!(
+            "{}\nabc1234,0,17,2,10,1500000,1500000,1500000,0\n{}\nnot,a,line\n",
+            HEADER,
+            e.to_line()
+        );
+        assert_eq!(History::parse(&contents).entries, vec![e]);
+    }
+
+    #[test]
+    fn test_compare() {
+        let history = History {
+            entries: vec![
+                entry("old", 17, 1, 100),
+                entry("old", 17, 2, 100),
+                entry("old", 19, 1, 100),
+                entry("new", 17, 1, 105),
+                entry("new", 17, 2, 130),
+                entry("new", 24, 1, 100),
+            ],
+        };
+        assert_eq!(history.latest_commit(), Some("new"));
+        assert_eq!(history.baseline_for("new"), Some("old"));
+
+        let comparisons = compare(&history, "new", "old", 0.1);
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change - 0.3).abs() < 1.0e-6);
+
+        let latest = history.latest_medians();
+        assert_eq!(latest[&(DEFAULT_YEAR, 17, 2)], Duration::from_micros(130));
+        assert_eq!(latest[&(DEFAULT_YEAR, 19, 1)], Duration::from_micros(100));
+    }
+}
@@ -0,0 +1,314 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::DEFAULT_YEAR;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::{env, fs};
+
+/// Environment variable that overrides the input file of a day, like `--input`.
+pub const INPUT_ENV: &str = "AOC_INPUT";
+
+/// Where to read a puzzle input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/<folder>/<day>.txt` in the year's directory, e.g. `src/inputs/01.txt`.
+    Default {
+        folder: String,
+        year: u16,
+        day: u8,
+    },
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    pub fn default_for(folder: &str, year: u16, day: u8) -> Self {
+        InputSource::Default {
+            folder: folder.to_string(),
+            year,
+            day,
+        }
+    }
+
+    /// Parses an `--input` value, where `-` stands for stdin.
+    pub fn from_arg(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(PathBuf::from(path)),
+        }
+    }
+
+    /// The input source for `day` given on the command line with `--input <path>`,
+    /// or in the `AOC_INPUT` environment variable. Returns `None` if neither is set.
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let value: Option<String> = args.opt_value_from_str("--input")?;
+        Ok(value
+            .or_else(|| env::var(INPUT_ENV).ok().filter(|v| !v.is_empty()))
+            .map(|value| Self::from_arg(&value)))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Default { folder, year, day } => {
+                write!(f, "{}", file_path(folder, *year, *day).display())
+            }
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// A puzzle input. Inputs in `src/<folder>/<day>/` are named after their file,
+/// the default input `src/<folder>/<day>.txt` and inputs given with `--input` have no name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub name: Option<String>,
+    pub text: String,
+}
+
+impl Input {
+    pub fn unnamed(text: String) -> Self {
+        Self { name: None, text }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// The default input file for a day does not exist.
+    Missing {
+        folder: String,
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    Io {
+        source: InputSource,
+        error: io::Error,
+    },
+}
+
+impl InputError {
+    pub fn is_missing(&self) -> bool {
+        matches!(self, InputError::Missing { .. })
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing {
+                folder,
+                year,
+                day,
+                path,
+            } if folder == "inputs" => write!(
+                f,
+                "could not find the input for day {} at \"{}\". Try running `cargo download {}{}` to download it.",
+                day,
+                path.display(),
+                day,
+                year_flag(*year)
+            ),
+            InputError::Missing { day, path, .. } => write!(
+                f,
+                "could not find the file for day {} at \"{}\".",
+                day,
+                path.display()
+            ),
+            InputError::Io { source, error } => {
+                write!(f, "could not read input from \"{}\": {}", source, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// The root directory of this crate. Input paths are resolved relative to it
+/// so that solutions can be run from any working directory.
+pub fn crate_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// The `--year` flag to append to suggested commands, empty for the default year.
+pub fn year_flag(year: u16) -> String {
+    if year == DEFAULT_YEAR {
+        String::new()
+    } else {
+        format!(" --year {}", year)
+    }
+}
+
+/// The directory holding the inputs, examples, puzzles and answers of `year`:
+/// `src/` for the default year and `src/<year>/` for every other event.
+pub fn year_dir(year: u16) -> PathBuf {
+    crate_root().join(relative_year_dir(year))
+}
+
+/// [`year_dir`] relative to the crate root.
+pub fn relative_year_dir(year: u16) -> PathBuf {
+    let src = PathBuf::from("src");
+    if year == DEFAULT_YEAR {
+        src
+    } else {
+        src.join(year.to_string())
+    }
+}
+
+pub fn file_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(folder).join(format!("{:02}.txt", day))
+}
+
+/// The path of the named input `src/<folder>/<day>/<name>.txt`.
+pub fn named_file_path(folder: &str, year: u16, day: u8, name: &str) -> PathBuf {
+    year_dir(year)
+        .join(folder)
+        .join(format!("{:02}", day))
+        .join(format!("{}.txt", name))
+}
+
+/// Lists the `.txt` files in `dir` by name, treating a missing directory as empty.
+fn named_files(dir: &Path) -> io::Result<Vec<(String, PathBuf)>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e),
+    };
+
+    let mut files = vec![];
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == "txt") {
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                files.push((name.to_string(), path.clone()));
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Reads the default input of `day` followed by all named inputs in `src/<folder>/<day>/`.
+/// Fails with [`InputError::Missing`] if the day has no input at all.
+pub fn read_inputs(folder: &str, year: u16, day: u8) -> Result<Vec<Input>, InputError> {
+    let mut inputs = vec![];
+    let missing = match read_input(&InputSource::default_for(folder, year, day)) {
+        Ok(text) => {
+            inputs.push(Input::unnamed(text));
+            None
+        }
+        Err(e) if e.is_missing() => Some(e),
+        Err(e) => return Err(e),
+    };
+
+    let dir = file_path(folder, year, day).with_extension("");
+    let files = named_files(&dir).map_err(|error| InputError::Io {
+        source: InputSource::Path(dir.clone()),
+        error,
+    })?;
+    for (name, path) in files {
+        let text = read_input(&InputSource::Path(path))?;
+        inputs.push(Input {
+            name: Some(name),
+            text,
+        });
+    }
+
+    match missing {
+        Some(e) if inputs.is_empty() => Err(e),
+        _ => Ok(inputs),
+    }
+}
+
+pub fn read_input(source: &InputSource) -> Result<String, InputError> {
+    let io_error = |error| InputError::Io {
+        source: source.clone(),
+        error,
+    };
+
+    match source {
+        InputSource::Default { folder, year, day } => {
+            let path = file_path(folder, *year, *day);
+            fs::read_to_string(&path).map_err(|error| match error.kind() {
+                io::ErrorKind::NotFound => InputError::Missing {
+                    folder: folder.clone(),
+                    year: *year,
+                    day: *day,
+                    path,
+                },
+                _ => io_error(error),
+            })
+        }
+        InputSource::Path(path) => fs::read_to_string(path).map_err(io_error),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map(|_| input)
+                .map_err(io_error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("in.txt"),
+            InputSource::Path(PathBuf::from("in.txt"))
+        );
+    }
+
+    #[test]
+    fn test_read_input() {
+        let input = read_input(&InputSource::default_for("examples", DEFAULT_YEAR, 1)).unwrap();
+        assert!(input.starts_with("1000"));
+
+        let error = read_input(&InputSource::default_for("inputs", DEFAULT_YEAR, 0)).unwrap_err();
+        assert!(error.is_missing());
+        assert!(error.to_string().contains("`cargo download 0`"));
+
+        let error = read_input(&InputSource::default_for("inputs", 2015, 0)).unwrap_err();
+        assert!(error.to_string().contains("src/2015/inputs/00.txt"));
+        assert!(error.to_string().contains("`cargo download 0 --year 2015`"));
+
+        let error = read_input(&InputSource::Path(PathBuf::from("does/not/exist"))).unwrap_err();
+        assert!(!error.is_missing());
+    }
+
+    #[test]
+    fn test_read_inputs() {
+        let inputs = read_inputs("examples", DEFAULT_YEAR, 1).unwrap();
+        assert_eq!(inputs[0].name, None);
+        assert!(read_inputs("inputs", DEFAULT_YEAR, 0)
+            .unwrap_err()
+            .is_missing());
+    }
+
+    #[test]
+    fn test_named_files() {
+        let dir = env::temp_dir().join(format!("aoc-named-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(dir.join(file), "").unwrap();
+        }
+        let names: Vec<String> = named_files(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(names, vec!["alice", "bob"]);
+        assert!(named_files(&dir).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,309 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_RESET};
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+/// A private leaderboard, as served by `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Leaderboard {
+    pub owner_id: u64,
+    pub event: String,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    /// Not set for members that don't share their name.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// The stars of the member, keyed by day and part.
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Completion>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Completion {
+    /// Seconds since the unix epoch.
+    pub get_star_ts: u64,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|e| format!("invalid leaderboard: {}", e))
+    }
+
+    /// The year of the event, falling back to `year` if the leaderboard doesn't say.
+    pub fn year(&self, year: u16) -> u16 {
+        self.event.parse().unwrap_or(year)
+    }
+
+    /// Members ranked by local score, then by stars, then by whoever got them first.
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| {
+            (
+                Reverse(m.local_score),
+                Reverse(m.stars),
+                m.last_star(),
+                m.id,
+            )
+        });
+        members
+    }
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// When the member got the star of a part.
+    pub fn completed(&self, day: u8, part: u8) -> Option<u64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+
+    fn last_star(&self) -> Option<u64> {
+        self.completion_day_level
+            .values()
+            .flat_map(|parts| parts.values())
+            .map(|completion| completion.get_star_ts)
+            .max()
+    }
+
+    /// Seconds between the stars of part one and part two of a day, `None` if the member
+    /// doesn't have both or, in inconsistent data, got part two first.
+    pub fn delta(&self, day: u8) -> Option<u64> {
+        self.completed(day, 2)?.checked_sub(self.completed(day, 1)?)
+    }
+
+    /// `*` for both stars of a day, `+` for part one only and `.` for none.
+    fn day_marker(&self, day: u8) -> char {
+        match (self.completed(day, 1), self.completed(day, 2)) {
+            (Some(_), Some(_)) => '*',
+            (Some(_), None) => '+',
+            _ => '.',
+        }
+    }
+}
+
+/// Days since the unix epoch of a date, see
+/// <http://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// When a puzzle unlocks: midnight EST, which is 05:00 UTC.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    (days_from_civil(year.into(), 12, day.into()) * 86400 + 5 * 3600) as u64
+}
+
+/// Formats seconds as `hh:mm:ss`, with more than 24 hours if needed.
+fn format_seconds(seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+/// The overview of all members: rank, local score, stars and the stars of every day.
+pub fn render_overview(leaderboard: &Leaderboard, year: u16) -> String {
+    let members = leaderboard.ranked();
+    let width = name_width(&members);
+    let last_day = members
+        .iter()
+        .flat_map(|m| m.completion_day_level.keys())
+        .max()
+        .copied()
+        .unwrap_or(1);
+
+    // days are labelled over two lines, tens above ones.
+    let digit = |digit: u8| char::from(b'0' + digit);
+    let tens: String = (1..=last_day)
+        .map(|day| if day < 10 { ' ' } else { digit(day / 10) })
+        .collect();
+    let ones: String = (1..=last_day).map(|day| digit(day % 10)).collect();
+    let mut out = format!(
+        "Private leaderboard of {} ({} members)\n\n",
+        year,
+        members.len()
+    );
+    if last_day >= 10 {
+        out += &format!(
+            "{}{}{}{}\n",
+            ANSI_BOLD,
+            " ".repeat(width + 22),
+            tens,
+            ANSI_RESET
+        );
+    }
+    out += &format!(
+        "{}{:>4}  {:<width$}  {:>5}  {:>5}  {}{}\n",
+        ANSI_BOLD,
+        "Rank",
+        "Name",
+        "Score",
+        "Stars",
+        ones,
+        ANSI_RESET,
+        width = width
+    );
+    for (i, member) in members.iter().enumerate() {
+        let days: String = (1..=last_day).map(|day| member.day_marker(day)).collect();
+        out += &format!(
+            "{:>4}  {:<width$}  {:>5}  {:>5}  {}\n",
+            i + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars,
+            days,
+            width = width
+        );
+    }
+    out += "\n* both parts, + part one only\n";
+    out
+}
+
+/// The completion times of one day, relative to the unlock of the puzzle, and the deltas
+/// between part one and part two. Members are ranked by their time for part two.
+pub fn render_day(leaderboard: &Leaderboard, year: u16, day: u8) -> String {
+    let unlock = unlock_time(year, day);
+    let mut members: Vec<&Member> = leaderboard
+        .members
+        .values()
+        .filter(|m| m.completed(day, 1).is_some())
+        .collect();
+    members.sort_by_key(|m| {
+        (
+            m.completed(day, 2).is_none(),
+            m.completed(day, 2),
+            m.completed(day, 1),
+            m.id,
+        )
+    });
+    let width = name_width(&members);
+    let time = |ts: Option<u64>| match ts {
+        Some(ts) => format_seconds(ts.saturating_sub(unlock)),
+        None => "-".to_string(),
+    };
+
+    let mut out = format!("Day {} of {}\n\n", day, year);
+    out += &format!(
+        "{}{:>4}  {:<width$}  {:>9}  {:>9}  {:>9}{}\n",
+        ANSI_BOLD,
+        "Rank",
+        "Name",
+        "Part 1",
+        "Part 2",
+        "Delta",
+        ANSI_RESET,
+        width = width
+    );
+    for (i, member) in members.iter().enumerate() {
+        out += &format!(
+            "{:>4}  {:<width$}  {:>9}  {:>9}  {:>9}\n",
+            i + 1,
+            member.display_name(),
+            time(member.completed(day, 1)),
+            time(member.completed(day, 2)),
+            member.delta(day).map_or("-".to_string(), format_seconds),
+            width = width
+        );
+    }
+    if members.is_empty() {
+        out += "Nobody has solved this day yet.\n";
+    }
+    out
+}
+
+fn name_width(members: &[&Member]) -> usize {
+    members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Name".len())
+}
+
+/// Where a downloaded leaderboard is kept, so it isn't fetched more than every 15 minutes.
+pub fn cache_path(year: u16, id: u64) -> PathBuf {
+    crate::input::year_dir(year).join(format!("leaderboard-{}.json", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("fixtures/leaderboard.json");
+
+    #[test]
+    fn test_parse() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        assert_eq!(leaderboard.year(2015), 2022);
+        let names: Vec<String> = leaderboard
+            .ranked()
+            .iter()
+            .map(|m| m.display_name())
+            .collect();
+        assert_eq!(names, vec!["Alice", "Bob", "(anonymous user #1003)"]);
+
+        let alice = &leaderboard.members["1001"];
+        assert_eq!(alice.completed(1, 2), Some(1669871400));
+        assert_eq!(alice.delta(1), Some(300));
+        assert_eq!(alice.delta(3), None);
+        assert!(Leaderboard::parse("<html>").is_err());
+    }
+
+    #[test]
+    fn test_delta() {
+        // a member who got part two before part one, which the API shouldn't serve but might.
+        let json = r#"{"id": 7, "name": null, "stars": 2, "local_score": 4,
+            "completion_day_level": {"1": {"1": {"get_star_ts": 200}, "2": {"get_star_ts": 100}}}}"#;
+        let member: Member = serde_json::from_str(json).unwrap();
+        assert_eq!(member.delta(1), None);
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(unlock_time(2022, 1), 1669870800);
+        assert_eq!(unlock_time(2015, 25), 1451019600);
+        assert_eq!(format_seconds(90061), "25:01:01");
+    }
+
+    #[test]
+    fn test_render_overview() {
+        let overview = render_overview(&Leaderboard::parse(FIXTURE).unwrap(), 2022);
+        assert!(overview.starts_with("Private leaderboard of 2022 (3 members)"));
+        assert!(overview.contains("   1  Alice                      13      5  **+\n"));
+        assert!(overview.contains("   2  Bob                        10      4  **.\n"));
+        assert!(overview.contains("   3  (anonymous user #1003)      0      0  ...\n"));
+    }
+
+    #[test]
+    fn test_render_day() {
+        let leaderboard = Leaderboard::parse(FIXTURE).unwrap();
+        let day = render_day(&leaderboard, 2022, 2);
+        assert!(day.contains("   1  Alice   00:10:00   00:20:00   00:10:00\n"));
+        assert!(day.contains("   2  Bob     00:30:00   01:20:00   00:50:00\n"));
+
+        let day = render_day(&leaderboard, 2022, 3);
+        assert!(day.contains("   1  Alice   00:01:00          -          -\n"));
+        assert!(!day.contains("Bob"));
+        assert!(render_day(&leaderboard, 2022, 4).contains("Nobody has solved this day yet."));
+    }
+}
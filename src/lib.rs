@@ -3,123 +3,118 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
-
+use std::fmt::{self, Display};
+use std::process;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub mod bench;
+pub mod cli;
+pub mod client;
+pub mod commands;
+pub mod error;
 pub mod helpers;
+pub mod history;
+pub mod input;
+pub mod leaderboard;
+pub mod params;
+pub mod progress;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solutions;
+pub mod submit;
+pub mod template;
+pub mod verify;
+
+/// The event this repository was started for. Its files live directly in `src/`,
+/// files of other years in `src/<year>/`.
+pub const DEFAULT_YEAR: u16 = 2022;
+
+pub const ANSI_ITALIC: Ansi = Ansi("\x1b[3m");
+pub const ANSI_BOLD: Ansi = Ansi("\x1b[1m");
+pub const ANSI_RESET: Ansi = Ansi("\x1b[0m");
+
+static COLOR: AtomicBool = AtomicBool::new(true);
+
+/// An ANSI escape code that is only written while colors are enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ansi(&'static str);
+
+impl Display for Ansi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if COLOR.load(Ordering::Relaxed) {
+            f.write_str(self.0)
+        } else {
+            Ok(())
+        }
+    }
+}
 
-pub const ANSI_ITALIC: &str = "\x1b[3m";
-pub const ANSI_BOLD: &str = "\x1b[1m";
-pub const ANSI_RESET: &str = "\x1b[0m";
+/// Turns colored output on or off for the whole process.
+pub fn set_color(enabled: bool) {
+    COLOR.store(enabled, Ordering::Relaxed);
+}
 
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
-        }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+    ($day:expr, $part:expr, $solver:ident, $input:expr) => {{
+        $crate::runner::solve($day, $part, $solver, $input)
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+pub use input::{
+    file_path, named_file_path, read_input, read_inputs, Input, InputError, InputSource,
+};
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Reads `src/<folder>/<day>.txt`, panicking with a helpful message if it can't be read.
+pub fn read_file(folder: &str, day: u8) -> String {
+    read_year_file(folder, DEFAULT_YEAR, day)
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Reads `src/<folder>/<day>.txt` of `year`, panicking with a helpful message if it can't be read.
+pub fn read_year_file(folder: &str, year: u16, day: u8) -> String {
+    read_input(&InputSource::default_for(folder, year, day)).unwrap_or_else(|e| panic!("{}", e))
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// Reads the named input `src/<folder>/<day>/<name>.txt`, panicking if it can't be read.
+pub fn read_named_file(folder: &str, day: u8, name: &str) -> String {
+    read_input(&InputSource::Path(named_file_path(
+        folder,
+        DEFAULT_YEAR,
+        day,
+        name,
+    )))
+    .unwrap_or_else(|e| panic!("{}", e))
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Reads the puzzle inputs for `day`: the input given with `--input <path>` (`-` for stdin)
+/// or the `AOC_INPUT` environment variable, otherwise `src/inputs/<day>.txt` and every
+/// named input in `src/inputs/<day>/`. Exits with an error message if nothing can be read.
+pub fn load_inputs(day: u8) -> Vec<Input> {
+    load_year_inputs(DEFAULT_YEAR, day)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+/// Like [`load_inputs`], for a day of another year.
+pub fn load_year_inputs(year: u16, day: u8) -> Vec<Input> {
+    let mut args = pico_args::Arguments::from_env();
+    let source = match InputSource::from_args(&mut args) {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let inputs = match source {
+        Some(source) => read_input(&source).map(|text| vec![Input::unnamed(text)]),
+        None => read_inputs("inputs", year, day),
+    };
+    match inputs {
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(1);
+        }
     }
 }
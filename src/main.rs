@@ -2,47 +2,21 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::error::Error;
+use advent_of_code::{cli, commands, runner};
+use std::{env, process};
 
 fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
-
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
-
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
-        .sum();
-
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+    // parts are run in child processes of this binary, so that they can be killed on a timeout.
+    if let Ok(exe) = env::current_exe() {
+        runner::isolate_in_subprocesses(exe);
+    }
+    let result = cli::parse(env::args_os().skip(1).collect()).and_then(commands::execute);
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        if matches!(e, Error::Args(_)) {
+            eprintln!("Run `aoc --help` for usage.");
+        }
+        process::exit(e.exit_code());
+    }
 }
@@ -0,0 +1,73 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::HashMap;
+use std::str::FromStr;
+
+const PREFIX: &str = "#!";
+
+/// Puzzle parameters that differ between the example and the real input,
+/// e.g. the row to scan on day 15 (10 in the example, 2000000 for real inputs).
+///
+/// Parameters are stored as a block of `#! key = value` lines at the start of an input file:
+///
+/// ```text
+/// #! row = 10
+/// #! limit = 20
+/// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+/// ```
+///
+/// Solutions read them with [`Params::split`] and fall back to the real puzzle's value
+/// when a parameter is not set, so real inputs don't need a block.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Params(HashMap<String, String>);
+
+impl Params {
+    /// Separates the parameter block from the rest of the input.
+    pub fn split(input: &str) -> (Self, &str) {
+        let mut params = HashMap::new();
+        let mut rest = input;
+        while let Some(line) = rest.strip_prefix(PREFIX) {
+            let (line, next) = line.split_once('\n').unwrap_or((line, ""));
+            if let Some((key, value)) = line.split_once('=') {
+                params.insert(key.trim().to_string(), value.trim().to_string());
+            }
+            rest = next;
+        }
+        (Self(params), rest)
+    }
+
+    /// Returns the parameter `key` parsed as `T`.
+    ///
+    /// Panics if the parameter is set but can't be parsed.
+    pub fn get<T: FromStr>(&self, key: &str) -> Option<T> {
+        self.0.get(key).map(|value| match value.parse() {
+            Ok(value) => value,
+            Err(_) => panic!("invalid value for parameter \"{}\": \"{}\"", key, value),
+        })
+    }
+
+    /// Returns the parameter `key` parsed as `T`, or `default` if it is not set.
+    pub fn get_or<T: FromStr>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split() {
+        let (params, rest) = Params::split("#! row = 10\n#!limit=20\r\nSensor at x=2\n#! no\n");
+        assert_eq!(params.get::<i32>("row"), Some(10));
+        assert_eq!(params.get::<i32>("limit"), Some(20));
+        assert_eq!(params.get_or("rounds", 7), 7);
+        assert_eq!(rest, "Sensor at x=2\n#! no\n");
+
+        let (params, rest) = Params::split("1000\n2000");
+        assert_eq!(params, Params::default());
+        assert_eq!(rest, "1000\n2000");
+    }
+}
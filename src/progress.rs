@@ -0,0 +1,218 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::verify::Verdict;
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// The marker the stars table follows, shared with the `readme-stars.yml` workflow.
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+/// The marker the benchmark table follows.
+pub const BENCH_MARKER: &str = "<!--- benchmarking table --->";
+
+/// The stars and the latest benchmarked median timings of the parts of a day.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DayProgress {
+    pub day: u8,
+    /// Indexed by part - 1.
+    pub stars: [bool; 2],
+    pub medians: [Option<Duration>; 2],
+}
+
+impl DayProgress {
+    pub fn star_count(&self) -> usize {
+        self.stars.iter().filter(|star| **star).count()
+    }
+}
+
+/// Whether a part earned its star: its answer was verified against the known answer, or
+/// there is a known answer but no input to check it against (e.g. in CI).
+pub fn has_star(verdict: &Verdict, has_input: bool, has_answer: bool) -> bool {
+    match verdict {
+        Verdict::Pass => true,
+        Verdict::Missing(_) => !has_input && has_answer,
+        Verdict::Fail { .. } => false,
+    }
+}
+
+fn day_link(year: u16, day: u8) -> String {
+    format!(
+        "[Day {}](https://adventofcode.com/{}/day/{})",
+        day, year, day
+    )
+}
+
+/// The stars table in the format of the `readme-stars.yml` workflow, so both can update it.
+pub fn stars_table(year: u16, days: &[DayProgress]) -> String {
+    let mut table = format!(
+        "## {} Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+    for day in days {
+        let cell = |star: bool| if star { "⭐" } else { " " };
+        table.push_str(&format!(
+            "| {} | {} | {} |\n",
+            day_link(year, day.day),
+            cell(day.stars[0]),
+            cell(day.stars[1])
+        ));
+    }
+    table
+}
+
+/// The median timings of all benchmarked parts and their total.
+pub fn bench_table(year: u16, days: &[DayProgress]) -> String {
+    let mut table = format!(
+        "## {} Benchmarks\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n",
+        year
+    );
+    for day in days {
+        let cell = |median: Option<Duration>| match median {
+            Some(median) => format!("`{:.2?}`", median),
+            None => "-".to_string(),
+        };
+        table.push_str(&format!(
+            "| {} | {} | {} |\n",
+            day_link(year, day.day),
+            cell(day.medians[0]),
+            cell(day.medians[1])
+        ));
+    }
+    let total: Duration = days.iter().flat_map(|day| day.medians).flatten().sum();
+    table.push_str(&format!(
+        "\n**Total: {:.2}ms**\n",
+        total.as_secs_f64() * 1000_f64
+    ));
+    table
+}
+
+/// Whether a line after a marker belongs to a generated table.
+fn is_generated(line: &str) -> bool {
+    let line = line.trim();
+    line.is_empty()
+        || line.starts_with('|')
+        || line.starts_with("**Total")
+        || (line.starts_with("## ")
+            && (line.ends_with(" Results") || line.ends_with(" Benchmarks")))
+}
+
+/// Replaces the table that follows `marker` in `readme` with `table`.
+pub fn replace_section(readme: &str, marker: &str, table: &str) -> Result<String, String> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == marker)
+        .ok_or_else(|| format!("there is no \"{}\" line to put the table after", marker))?;
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            !is_generated(line) || line.trim() == STARS_MARKER || line.trim() == BENCH_MARKER
+        })
+        .map_or(lines.len(), |i| start + 1 + i);
+
+    let mut replaced: Vec<String> = lines[..=start]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+    replaced.push(table.trim_end().to_string());
+    replaced.push(String::new());
+    replaced.extend(lines[end..].iter().map(|line| line.to_string()));
+    Ok(replaced.join("\n") + "\n")
+}
+
+pub fn readme_path() -> PathBuf {
+    crate::input::crate_root().join("README.md")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days() -> Vec<DayProgress> {
+        vec![
+            DayProgress {
+                day: 1,
+                stars: [true, true],
+                medians: [
+                    Some(Duration::from_micros(40)),
+                    Some(Duration::from_micros(60)),
+                ],
+            },
+            DayProgress {
+                day: 2,
+                stars: [true, false],
+                medians: [Some(Duration::from_millis(2)), None],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_stars_table() {
+        assert_eq!(
+            stars_table(2022, &days()),
+            "## 2022 Results\n\n\
+             | Day | Part 1 | Part 2 |\n\
+             | :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 2](https://adventofcode.com/2022/day/2) | ⭐ |   |\n"
+        );
+    }
+
+    #[test]
+    fn test_bench_table() {
+        let table = bench_table(2022, &days());
+        assert!(table
+            .contains("| [Day 1](https://adventofcode.com/2022/day/1) | `40.00µs` | `60.00µs` |"));
+        assert!(table.contains("| [Day 2](https://adventofcode.com/2022/day/2) | `2.00ms` | - |"));
+        assert!(table.ends_with("\n**Total: 2.10ms**\n"));
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!(
+            "# AoC\n\n{}\n{}\n\n---\n\nText\n",
+            STARS_MARKER, BENCH_MARKER
+        );
+        let stars = stars_table(2022, &days());
+        let bench = bench_table(2022, &days());
+
+        let updated = replace_section(&readme, STARS_MARKER, &stars).unwrap();
+        let updated = replace_section(&updated, BENCH_MARKER, &bench).unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# AoC\n\n{}\n{}\n{}\n{}\n---\n\nText\n",
+                STARS_MARKER, stars, BENCH_MARKER, bench
+            )
+        );
+        // regenerating replaces the tables instead of adding them again.
+        let again =
+            replace_section(&updated, STARS_MARKER, &stars_table(2022, &days()[..1])).unwrap();
+        let again = replace_section(&again, BENCH_MARKER, &bench).unwrap();
+        assert!(!again.contains("Day 2](https://adventofcode.com/2022/day/2) | ⭐"));
+        assert_eq!(again.matches("## 2022 Benchmarks").count(), 1);
+        assert!(again.ends_with("**Total: 2.10ms**\n\n---\n\nText\n"));
+
+        assert!(replace_section("# AoC\n", BENCH_MARKER, &bench).is_err());
+    }
+
+    #[test]
+    fn test_has_star() {
+        assert!(has_star(&Verdict::Pass, true, true));
+        assert!(!has_star(
+            &Verdict::Missing("no expected answer"),
+            true,
+            false
+        ));
+        assert!(has_star(&Verdict::Missing("no input"), false, true));
+        assert!(!has_star(
+            &Verdict::Fail {
+                expected: "1".to_string(),
+                actual: None
+            },
+            true,
+            true
+        ));
+    }
+}
@@ -0,0 +1,416 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::client::BASE_URL;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::path::PathBuf;
+
+const VOID_ELEMENTS: [&str; 6] = ["br", "hr", "img", "input", "link", "meta"];
+
+/// A node of a parsed HTML document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Node {
+    Text(String),
+    Element {
+        name: String,
+        attrs: Vec<(String, String)>,
+        children: Vec<Node>,
+    },
+}
+
+impl Node {
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Node::Element { name, .. } => Some(name),
+            Node::Text(_) => None,
+        }
+    }
+
+    pub fn attr(&self, key: &str) -> Option<&str> {
+        match self {
+            Node::Element { attrs, .. } => attrs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str()),
+            Node::Text(_) => None,
+        }
+    }
+
+    pub fn children(&self) -> &[Node] {
+        match self {
+            Node::Element { children, .. } => children,
+            Node::Text(_) => &[],
+        }
+    }
+
+    /// The concatenated text of this node and its descendants.
+    pub fn text(&self) -> String {
+        match self {
+            Node::Text(text) => text.clone(),
+            Node::Element { children, .. } => children.iter().map(Node::text).collect(),
+        }
+    }
+
+    /// All descendants (including this node) named `name`, in document order.
+    pub fn find_all<'a>(&'a self, name: &str, found: &mut Vec<&'a Node>) {
+        if self.name() == Some(name) {
+            found.push(self);
+        }
+        for child in self.children() {
+            child.find_all(name, found);
+        }
+    }
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+fn parse_attrs(attrs: &str) -> Vec<(String, String)> {
+    lazy_static! {
+        static ref ATTR: Regex =
+            Regex::new(r#"([a-zA-Z_:-]+)(?:\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+)))?"#).unwrap();
+    }
+    ATTR.captures_iter(attrs)
+        .map(|c| {
+            let value = c
+                .get(2)
+                .or(c.get(3))
+                .or(c.get(4))
+                .map_or("", |m| m.as_str());
+            (c[1].to_lowercase(), decode_entities(value))
+        })
+        .collect()
+}
+
+/// An element that is still open while parsing: `(name, attrs, children)`.
+type OpenElement = (String, Vec<(String, String)>, Vec<Node>);
+
+/// Closes the innermost open element and adds it to its parent.
+fn close(stack: &mut Vec<OpenElement>) {
+    let (name, attrs, children) = stack.pop().unwrap();
+    stack.last_mut().unwrap().2.push(Node::Element {
+        name,
+        attrs,
+        children,
+    });
+}
+
+/// Parses an HTML document into a tree. This is not a full HTML parser, but it
+/// handles the markup of Advent of Code pages, including unclosed tags.
+pub fn parse(html: &str) -> Node {
+    lazy_static! {
+        static ref TOKEN: Regex = Regex::new(
+            r#"(?s)<!--.*?-->|<!.*?>|<(/?)([a-zA-Z][a-zA-Z0-9]*)((?:[^>"']|"[^"]*"|'[^']*')*?)(/?)>"#
+        )
+        .unwrap();
+    }
+
+    // the document itself is the unnamed root element.
+    let mut stack: Vec<OpenElement> = vec![(String::new(), vec![], vec![])];
+    let mut last = 0;
+
+    for captures in TOKEN.captures_iter(html) {
+        let token = captures.get(0).unwrap();
+        let text = &html[last..token.start()];
+        if !text.is_empty() {
+            stack
+                .last_mut()
+                .unwrap()
+                .2
+                .push(Node::Text(decode_entities(text)));
+        }
+        last = token.end();
+
+        let name = match captures.get(2) {
+            Some(name) => name.as_str().to_lowercase(),
+            // comments and doctypes
+            None => continue,
+        };
+
+        if &captures[1] == "/" {
+            // closing tags without a matching open element are ignored.
+            if let Some(index) = stack.iter().rposition(|(open, _, _)| *open == name) {
+                while stack.len() > index.max(1) {
+                    close(&mut stack);
+                }
+            }
+        } else if &captures[4] == "/" || VOID_ELEMENTS.contains(&name.as_str()) {
+            stack.last_mut().unwrap().2.push(Node::Element {
+                name,
+                attrs: parse_attrs(&captures[3]),
+                children: vec![],
+            });
+        } else {
+            stack.push((name, parse_attrs(&captures[3]), vec![]));
+        }
+    }
+
+    let text = &html[last..];
+    if !text.is_empty() {
+        stack
+            .last_mut()
+            .unwrap()
+            .2
+            .push(Node::Text(decode_entities(text)));
+    }
+    while stack.len() > 1 {
+        close(&mut stack);
+    }
+
+    let (name, attrs, children) = stack.pop().unwrap();
+    Node::Element {
+        name,
+        attrs,
+        children,
+    }
+}
+
+/// The puzzle descriptions of a puzzle page, one per unlocked part.
+pub fn articles(document: &Node) -> Vec<&Node> {
+    let mut articles = vec![];
+    document.find_all("article", &mut articles);
+    articles.retain(|article| article.attr("class") == Some("day-desc"));
+    articles
+}
+
+fn escape(text: &str) -> String {
+    lazy_static! {
+        static ref WHITESPACE: Regex = Regex::new(r"\s+").unwrap();
+        static ref SPECIAL: Regex = Regex::new(r"([\\`*_\[\]])").unwrap();
+    }
+    let text = WHITESPACE.replace_all(text, " ");
+    SPECIAL.replace_all(&text, r"\$1").to_string()
+}
+
+fn code_span(code: &str) -> String {
+    if code.contains('`') {
+        format!("`` {} ``", code)
+    } else {
+        format!("`{}`", code)
+    }
+}
+
+fn inline(nodes: &[Node]) -> String {
+    nodes.iter().map(inline_node).collect()
+}
+
+fn inline_node(node: &Node) -> String {
+    let name = match node {
+        Node::Text(text) => return escape(text),
+        Node::Element { name, .. } => name.as_str(),
+    };
+    let children = node.children();
+
+    match name {
+        "em" | "strong" | "b" | "i" => {
+            let content = inline(children);
+            let trimmed = content.trim();
+            if trimmed.is_empty() {
+                return content;
+            }
+            let marker = if name == "em" || name == "i" {
+                "*"
+            } else {
+                "**"
+            };
+            // keep surrounding whitespace outside of the markers, or they won't render.
+            let leading = if content.starts_with(' ') { " " } else { "" };
+            let trailing = if content.ends_with(' ') { " " } else { "" };
+            format!("{}{}{}{}{}", leading, marker, trimmed, marker, trailing)
+        }
+        "code" => {
+            let code = code_span(&node.text());
+            let mut highlighted = vec![];
+            node.find_all("em", &mut highlighted);
+            if highlighted.is_empty() {
+                code
+            } else {
+                format!("**{}**", code)
+            }
+        }
+        "a" => {
+            let content = inline(children);
+            match node.attr("href") {
+                Some(href) if href.starts_with('/') => {
+                    format!("[{}]({}{})", content, BASE_URL, href)
+                }
+                Some(href) => format!("[{}]({})", content, href),
+                None => content,
+            }
+        }
+        "br" => "\\\n".to_string(),
+        _ => inline(children),
+    }
+}
+
+fn block(node: &Node) -> Option<String> {
+    let markdown = match node {
+        Node::Text(text) if text.trim().is_empty() => return None,
+        Node::Text(text) => escape(text.trim()),
+        Node::Element { name, children, .. } => match name.as_str() {
+            "h1" | "h2" | "h3" => format!(
+                "{} {}",
+                "#".repeat(name[1..].parse().unwrap()),
+                inline(children).trim()
+            ),
+            "pre" => format!("```\n{}\n```", node.text().trim_end_matches('\n')),
+            "ul" | "ol" => {
+                let mut items = vec![];
+                node.find_all("li", &mut items);
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let marker = if name == "ol" {
+                            format!("{}.", i + 1)
+                        } else {
+                            "-".to_string()
+                        };
+                        format!("{} {}", marker, inline(item.children()).trim())
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            }
+            _ => inline(children).trim().to_string(),
+        },
+    };
+    Some(markdown)
+}
+
+/// Converts the puzzle descriptions of a puzzle page to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let document = parse(html);
+    let blocks: Vec<String> = articles(&document)
+        .iter()
+        .flat_map(|article| article.children().iter().filter_map(block))
+        .collect();
+    blocks.join("\n\n") + "\n"
+}
+
+/// The example input and expected answers found in a puzzle description.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The first `<pre><code>` block of the puzzle.
+    pub input: Option<String>,
+    /// The last highlighted answer (`<code><em>...</em></code>`) in the description of each part.
+    pub answers: [Option<String>; 2],
+}
+
+/// Extracts the example input and its expected answers from a puzzle page.
+pub fn extract_example(html: &str) -> Example {
+    let document = parse(html);
+    let articles = articles(&document);
+    let mut example = Example::default();
+
+    let mut blocks = vec![];
+    for article in articles.iter() {
+        article.find_all("pre", &mut blocks);
+    }
+    example.input = blocks
+        .iter()
+        .find(|pre| pre.children().iter().any(|c| c.name() == Some("code")))
+        .map(|pre| pre.text());
+
+    for (answer, article) in example.answers.iter_mut().zip(articles.iter()) {
+        let mut codes = vec![];
+        article.find_all("code", &mut codes);
+        *answer = codes
+            .iter()
+            .rev()
+            .find(|code| code.children().iter().any(|c| c.name() == Some("em")))
+            .map(|code| code.text().trim().to_string());
+    }
+    example
+}
+
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::input::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("fixtures/puzzle.html");
+
+    #[test]
+    fn test_parse() {
+        let document = parse("<p class=\"a\">x &amp; <em>y</em><br/>z</b></p><!-- c -->");
+        let mut paragraphs = vec![];
+        document.find_all("p", &mut paragraphs);
+        assert_eq!(paragraphs.len(), 1);
+        assert_eq!(paragraphs[0].attr("class"), Some("a"));
+        assert_eq!(paragraphs[0].text(), "x & yz");
+        assert_eq!(paragraphs[0].children().len(), 4);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = to_markdown(FIXTURE);
+        assert_eq!(
+            markdown,
+            "## --- Day 1: Calorie Counting ---
+
+The Elves each carry a number of *Calories* & snacks. One Elf writes them down like this (see [about](https://adventofcode.com/2022/about)):
+
+```
+1000
+2000
+3000
+
+4000
+```
+
+This list represents the Calories carried by the Elves:
+
+- The first Elf is carrying `1000`, `2000`, and `3000` Calories, a total of **`6000`** Calories.
+- The second Elf is carrying one food item with `4000` Calories.
+
+In the example above, this is *`6000`* (carried by the first Elf).
+
+Find the Elf carrying the most Calories. *How many total Calories is that Elf carrying?*
+
+## --- Part Two ---
+
+Now find the top *three* Elves.\\
+In the example above, their total is **`45000`** Calories.
+"
+        );
+    }
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(
+            extract_example(FIXTURE),
+            Example {
+                input: Some("1000\n2000\n3000\n\n4000\n".to_string()),
+                answers: [Some("6000".to_string()), Some("45000".to_string())],
+            }
+        );
+
+        let part_one = FIXTURE.split("<p>Your puzzle answer").next().unwrap();
+        assert_eq!(
+            extract_example(part_one).answers,
+            [Some("6000".to_string()), None]
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            to_markdown("<article class=\"day-desc\"><p>a_b [c]\n *d*</p></article>"),
+            "a\\_b \\[c\\] \\*d\\*\n"
+        );
+    }
+}
@@ -0,0 +1,279 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::{total_elapsed, PartResult, Status};
+use crate::DEFAULT_YEAR;
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Machine-readable output formats for the all-days runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+    Junit,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "junit" => Ok(Format::Junit),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: json, csv, junit",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    input: Option<&'a str>,
+    status: &'static str,
+    answer: Option<&'a str>,
+    elapsed_ns: u128,
+    error: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    results: Vec<JsonResult<'a>>,
+    total_ns: u128,
+}
+
+pub fn render(format: Format, results: &[PartResult]) -> String {
+    match format {
+        Format::Json => to_json(results),
+        Format::Csv => to_csv(results),
+        Format::Junit => to_junit(results),
+    }
+}
+
+fn to_json(results: &[PartResult]) -> String {
+    let report = JsonReport {
+        results: results
+            .iter()
+            .map(|result| JsonResult {
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                input: result.input.as_deref(),
+                status: result.status.as_str(),
+                answer: result.is_solved().then_some(result.answer.as_str()),
+                elapsed_ns: result.elapsed.as_nanos(),
+                error: result.error.as_deref(),
+            })
+            .collect(),
+        total_ns: total_elapsed(results).as_nanos(),
+    };
+    serde_json::to_string_pretty(&report).unwrap()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(results: &[PartResult]) -> String {
+    let mut out = String::from("year,day,part,input,status,answer,elapsed_ns,error\n");
+    for result in results {
+        out += &format!(
+            "{},{},{},{},{},{},{},{}\n",
+            result.year,
+            result.day,
+            result.part,
+            csv_field(result.input.as_deref().unwrap_or("")),
+            result.status.as_str(),
+            csv_field(&result.answer),
+            result.elapsed.as_nanos(),
+            csv_field(result.error.as_deref().unwrap_or(""))
+        );
+    }
+    out += &format!("total,,,,,,{},\n", total_elapsed(results).as_nanos());
+    out
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// `day05` for the default year and `year2021.day05` for other years.
+fn suite_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{:02}", day)
+    } else {
+        format!("year{}.day{:02}", year, day)
+    }
+}
+
+fn to_junit(results: &[PartResult]) -> String {
+    let count = |results: &[&PartResult], status: Status| {
+        results.iter().filter(|r| r.status == status).count()
+    };
+    let failures = |results: &[&PartResult]| results.iter().filter(|r| r.is_failure()).count();
+    let all: Vec<&PartResult> = results.iter().collect();
+
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    out += &format!(
+        "<testsuites name=\"advent_of_code\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+        all.len(),
+        failures(&all),
+        count(&all, Status::Unsolved),
+        total_elapsed(results).as_secs_f64()
+    );
+
+    let mut days: Vec<(u16, u8)> = results.iter().map(|r| (r.year, r.day)).collect();
+    days.dedup();
+
+    for (year, day) in days {
+        let parts: Vec<&PartResult> = results
+            .iter()
+            .filter(|r| r.year == year && r.day == day)
+            .collect();
+        let time: Duration = parts.iter().map(|r| r.elapsed).sum();
+        let suite = suite_name(year, day);
+        out += &format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+            suite,
+            parts.len(),
+            failures(&parts),
+            count(&parts, Status::Unsolved),
+            time.as_secs_f64()
+        );
+        for result in parts {
+            let name = match &result.input {
+                Some(input) => format!("part_{}[{}]", result.part, xml_escape(input)),
+                None => format!("part_{}", result.part),
+            };
+            out += &format!(
+                "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\">\n",
+                suite,
+                name,
+                result.elapsed.as_secs_f64()
+            );
+            match result.status {
+                Status::Solved => {
+                    out += &format!(
+                        "      <system-out>{}</system-out>\n",
+                        xml_escape(&result.answer)
+                    )
+                }
+                Status::Unsolved => out += "      <skipped message=\"not solved\"/>\n",
+                Status::Panicked | Status::TimedOut => {
+                    let error = xml_escape(result.error.as_deref().unwrap_or_default());
+                    out += &format!(
+                        "      <failure type=\"{}\" message=\"{}\"/>\n",
+                        result.status.as_str(),
+                        error.lines().next().unwrap_or_default()
+                    );
+                    out += &format!("      <system-err>{}</system-err>\n", error);
+                }
+            }
+            out += "    </testcase>\n";
+        }
+        out += "  </testsuite>\n";
+    }
+    out += "</testsuites>\n";
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn results() -> Vec<PartResult> {
+        vec![
+            PartResult {
+                year: DEFAULT_YEAR,
+                day: 5,
+                part: 1,
+                input: None,
+                answer: "C,\"M\"".to_string(),
+                elapsed: Duration::from_nanos(1500),
+                status: Status::Solved,
+                error: None,
+            },
+            PartResult {
+                year: DEFAULT_YEAR,
+                day: 5,
+                part: 2,
+                input: Some("alice".to_string()),
+                answer: String::new(),
+                elapsed: Duration::from_nanos(20),
+                status: Status::Unsolved,
+                error: None,
+            },
+            PartResult {
+                year: 2021,
+                day: 6,
+                part: 1,
+                input: None,
+                answer: String::new(),
+                elapsed: Duration::from_nanos(30),
+                status: Status::Panicked,
+                error: Some("panicked at src/solutions/day06.rs:1:1:\n<oh no>".to_string()),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_parse_format() {
+        assert_eq!("junit".parse::<Format>(), Ok(Format::Junit));
+        assert!("xml".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_json() {
+        let value: serde_json::Value = serde_json::from_str(&to_json(&results())).unwrap();
+        assert_eq!(value["total_ns"], 1500);
+        assert_eq!(value["results"][0]["answer"], "C,\"M\"");
+        assert_eq!(value["results"][1]["status"], "unsolved");
+        assert!(value["results"][1]["answer"].is_null());
+        assert!(value["results"][0]["input"].is_null());
+        assert_eq!(value["results"][1]["input"], "alice");
+        assert_eq!(value["results"][2]["status"], "panicked");
+        assert_eq!(value["results"][2]["year"], 2021);
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            to_csv(&results()),
+            "year,day,part,input,status,answer,elapsed_ns,error\n2022,5,1,,solved,\"C,\"\"M\"\"\",1500,\n2022,5,2,alice,unsolved,,20,\n2021,6,1,,panicked,,30,\"panicked at src/solutions/day06.rs:1:1:\n<oh no>\"\ntotal,,,,,,1500,\n"
+        );
+    }
+
+    #[test]
+    fn test_junit() {
+        let xml = to_junit(&results());
+        assert!(xml.contains(
+            "<testsuites name=\"advent_of_code\" tests=\"3\" failures=\"1\" skipped=\"1\""
+        ));
+        assert!(xml.contains("<testsuite name=\"day05\" tests=\"2\""));
+        assert!(xml.contains("<system-out>C,&quot;M&quot;</system-out>"));
+        assert!(xml.contains("<skipped message=\"not solved\"/>"));
+        assert!(xml.contains("name=\"part_2[alice]\""));
+        assert!(xml.contains("<testsuite name=\"year2021.day06\" tests=\"1\""));
+        assert!(xml.contains(
+            "<failure type=\"panicked\" message=\"panicked at src/solutions/day06.rs:1:1:\"/>"
+        ));
+        assert!(xml.contains("&lt;oh no&gt;</system-err>"));
+    }
+}
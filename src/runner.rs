@@ -0,0 +1,601 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::bench::{self, BenchOptions};
+use crate::input::Input;
+use crate::solutions::{Solution, Solver};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, DEFAULT_YEAR};
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt::{self, Display};
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{self, Stdio};
+use std::sync::{mpsc, Arc, Once, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        [
+            Status::Solved,
+            Status::Unsolved,
+            Status::Panicked,
+            Status::TimedOut,
+        ]
+        .into_iter()
+        .find(|status| status.as_str() == s)
+    }
+
+    /// Whether the part crashed or hung, as opposed to returning an answer or `None`.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Status::Panicked | Status::TimedOut)
+    }
+}
+
+/// The outcome of running one part of a day's solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The name of the input the part ran against, `None` for the default input.
+    pub input: Option<String>,
+    /// The answer as displayed, empty if the part is not solved.
+    pub answer: String,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// The panic message or timeout of a failed part.
+    pub error: Option<String>,
+}
+
+impl PartResult {
+    pub fn is_solved(&self) -> bool {
+        self.status == Status::Solved
+    }
+
+    pub fn is_failure(&self) -> bool {
+        self.status.is_failure()
+    }
+
+    /// The `🎄 Part N 🎄` header, including the input name for named inputs.
+    pub fn header(&self) -> String {
+        match &self.input {
+            Some(name) => format!(
+                "🎄 {}Part {}{} ({}) 🎄",
+                ANSI_BOLD, self.part, ANSI_RESET, name
+            ),
+            None => format!("🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET),
+        }
+    }
+}
+
+impl Display for PartResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.header())?;
+        match self.status {
+            Status::Solved => write!(
+                f,
+                "{} {}(elapsed: {:.2?}){}",
+                self.answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            ),
+            Status::Unsolved => write!(f, "not solved."),
+            Status::Panicked => write!(
+                f,
+                "{}{}{}",
+                ANSI_BOLD,
+                self.error.as_deref().unwrap_or("panicked."),
+                ANSI_RESET
+            ),
+            Status::TimedOut => write!(
+                f,
+                "{}{}.{}",
+                ANSI_BOLD,
+                self.error.as_deref().unwrap_or("timed out"),
+                ANSI_RESET
+            ),
+        }
+    }
+}
+
+/// Runs one part of a solution against `input` and times it.
+pub fn run_part<T: Display>(
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&str) -> Option<T>,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let result = func(input);
+    let elapsed = timer.elapsed();
+
+    let (answer, status) = match result {
+        Some(answer) => (answer.to_string(), Status::Solved),
+        None => (String::new(), Status::Unsolved),
+    };
+
+    PartResult {
+        year: DEFAULT_YEAR,
+        day,
+        part,
+        input: None,
+        answer,
+        elapsed,
+        status,
+        error: None,
+    }
+}
+
+thread_local! {
+    /// Set on threads started by [`run_isolated`], whose panics are captured instead of printed.
+    static CAPTURE_PANICS: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// The binary [`run_isolated`] runs parts in, see [`isolate_in_subprocesses`].
+static SUBPROCESS_EXE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Marks the line of a child process's stdout that holds the result of its part.
+const RESULT_PREFIX: &str = "aoc-part-result: ";
+
+/// Makes [`run_isolated`] run every part in a child process of `exe`, which must be the `aoc`
+/// binary. Unlike a thread, a child process can be killed when it times out, and everything
+/// the part writes to stderr is captured, whichever thread wrote it.
+pub fn isolate_in_subprocesses(exe: PathBuf) {
+    let _ = SUBPROCESS_EXE.set(exe);
+}
+
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CAPTURE_PANICS.with(Cell::get) {
+                PANIC_MESSAGE.with(|message| *message.borrow_mut() = Some(info.to_string()));
+            } else {
+                default_hook(info);
+            }
+        }));
+    });
+}
+
+/// The message a panic was started with, e.g. by `panic!("oh no")`.
+fn payload_message(payload: &(dyn Any + Send)) -> Option<String> {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+}
+
+fn failed(day: u8, part: u8, elapsed: Duration, status: Status, error: String) -> PartResult {
+    PartResult {
+        year: DEFAULT_YEAR,
+        day,
+        part,
+        input: None,
+        answer: String::new(),
+        elapsed,
+        status,
+        error: Some(error),
+    }
+}
+
+fn timed_out(day: u8, part: u8, timeout: Duration) -> PartResult {
+    let error = format!("timed out after {:.2?}", timeout);
+    failed(day, part, timeout, Status::TimedOut, error)
+}
+
+/// Runs one part of a solution so that a panic or a hang doesn't take down the caller.
+/// Panics are reported as [`Status::Panicked`] with the panic message and, when run in a
+/// child process, with everything else the part wrote to stderr. If `timeout` elapses first,
+/// the part is reported as [`Status::TimedOut`].
+///
+/// Parts run in a child process after [`isolate_in_subprocesses`], and on a thread of this
+/// process otherwise. A thread can't be stopped, so a thread that timed out keeps running
+/// in the background until the process exits, and slows down the parts after it.
+pub fn run_isolated(
+    solution: &Solution,
+    part: u8,
+    input: Arc<str>,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let mut result = match SUBPROCESS_EXE.get() {
+        Some(exe) => run_subprocess(exe, solution, part, &input, timeout),
+        None => {
+            let (_, solver) = solution.parts()[part as usize - 1];
+            run_thread(solution.day, part, solver, input, timeout)
+        }
+    };
+    result.year = solution.year;
+    result
+}
+
+fn run_thread(
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input: Arc<str>,
+    timeout: Option<Duration>,
+) -> PartResult {
+    install_panic_hook();
+
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .name(format!("day{:02}-part{}", day, part))
+        .spawn(move || {
+            CAPTURE_PANICS.with(|capture| capture.set(true));
+            let timer = Instant::now();
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| run_part(day, part, solver, &input)));
+            let result = result.unwrap_or_else(|payload| {
+                // the hook only sees panics on this thread, e.g. not those of rayon workers,
+                // which are resumed here with their payload.
+                let error = PANIC_MESSAGE
+                    .with(|message| message.borrow_mut().take())
+                    .or_else(|| payload_message(payload.as_ref()))
+                    .unwrap_or_else(|| "panicked.".to_string());
+                failed(day, part, timer.elapsed(), Status::Panicked, error)
+            });
+            // the receiver is gone if the part timed out.
+            let _ = sender.send(result);
+        });
+
+    if let Err(e) = spawned {
+        eprintln!(
+            "Failed to spawn a thread for day {} part {}: {}",
+            day, part, e
+        );
+        process::exit(1);
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+    received.unwrap_or_else(|| timed_out(day, part, timeout.unwrap_or_default()))
+}
+
+/// Reads all of `reader` on another thread, so that a child process never blocks on a full pipe.
+fn read_in_background(mut reader: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut bytes = Vec::new();
+        let _ = reader.read_to_end(&mut bytes);
+        String::from_utf8_lossy(&bytes).into_owned()
+    })
+}
+
+/// Runs a part with `aoc run-part`, which is killed if it doesn't finish within `timeout`.
+fn run_subprocess(
+    exe: &Path,
+    solution: &Solution,
+    part: u8,
+    input: &Arc<str>,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let spawned = process::Command::new(exe)
+        .arg("--year")
+        .arg(solution.year.to_string())
+        .arg("run-part")
+        .arg(solution.day.to_string())
+        .arg(part.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn();
+    let mut child = match spawned {
+        Ok(child) => child,
+        Err(e) => {
+            eprintln!(
+                "Failed to start a process for day {} part {}: {}",
+                solution.day, part, e
+            );
+            process::exit(1);
+        }
+    };
+
+    let timer = Instant::now();
+    if let Some(mut stdin) = child.stdin.take() {
+        let input = input.clone();
+        // the pipe is closed when the thread ends, which ends the input.
+        thread::spawn(move || stdin.write_all(input.as_bytes()));
+    }
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+
+    let exit = loop {
+        match child.try_wait() {
+            Ok(None) if timeout.is_some_and(|timeout| timer.elapsed() >= timeout) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                break None;
+            }
+            Ok(None) => thread::sleep(Duration::from_millis(1)),
+            Ok(Some(status)) => break Some(status),
+            Err(_) => break child.wait().ok(),
+        }
+    };
+    let elapsed = timer.elapsed();
+    let read = |output: Option<thread::JoinHandle<String>>| {
+        output
+            .and_then(|output| output.join().ok())
+            .unwrap_or_default()
+    };
+    let (stdout, stderr) = (read(stdout), read(stderr));
+
+    match exit {
+        None => {
+            let mut result = timed_out(solution.day, part, timeout.unwrap_or_default());
+            result.error = result
+                .error
+                .map(|error| with_output(error, &stdout, &stderr));
+            result
+        }
+        Some(status) => child_result(solution.day, part, &stdout, &stderr).unwrap_or_else(|| {
+            // the part didn't get to report a result, e.g. on a stack overflow.
+            let error = with_output(format!("exited with {}", status), &stdout, &stderr);
+            failed(solution.day, part, elapsed, Status::Panicked, error)
+        }),
+    }
+}
+
+/// Appends what a child process wrote besides its result to an error message.
+fn with_output(error: String, stdout: &str, stderr: &str) -> String {
+    let (printed, _) = split_result(stdout);
+    match (printed.to_string() + stderr).trim_end() {
+        "" => error,
+        output => format!("{}\n{}", error, output),
+    }
+}
+
+/// Splits the stdout of a child process into what the part printed itself and the result
+/// line, without the newline [`serve_part`] puts before the result.
+fn split_result(stdout: &str) -> (&str, Option<&str>) {
+    let marker = format!("\n{}", RESULT_PREFIX);
+    match stdout.rfind(&marker) {
+        Some(i) => {
+            let line = stdout[i + marker.len()..].lines().next().unwrap_or("");
+            (&stdout[..i], Some(line))
+        }
+        None => (stdout, None),
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ChildResult {
+    status: String,
+    answer: String,
+    elapsed_ns: u64,
+}
+
+/// The result a child process reported on the last result line of its stdout. The output
+/// of a panicked part is kept as its error, and that of other parts is passed on to stderr.
+fn child_result(day: u8, part: u8, stdout: &str, stderr: &str) -> Option<PartResult> {
+    let (printed, line) = split_result(stdout);
+    let reported: ChildResult = serde_json::from_str(line?).ok()?;
+    let status = Status::parse(&reported.status)?;
+    let output = printed.to_string() + stderr;
+    let error = match status {
+        Status::Panicked => Some(match output.trim_end() {
+            "" => "panicked.".to_string(),
+            output => output.to_string(),
+        }),
+        _ => {
+            eprint!("{}", output);
+            None
+        }
+    };
+    Some(PartResult {
+        year: DEFAULT_YEAR,
+        day,
+        part,
+        input: None,
+        answer: reported.answer,
+        elapsed: Duration::from_nanos(reported.elapsed_ns),
+        status,
+        error,
+    })
+}
+
+/// The child process side of [`isolate_in_subprocesses`]: runs a part against the input on
+/// stdin and prints its result as the last line of stdout. Panics are printed to stderr.
+pub fn serve_part(day: u8, part: u8, solver: Solver) -> io::Result<()> {
+    panic::set_hook(Box::new(|info| eprintln!("{}", info)));
+    let mut input = String::new();
+    io::stdin().read_to_string(&mut input)?;
+
+    let timer = Instant::now();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| run_part(day, part, solver, &input)))
+        .unwrap_or_else(|_| failed(day, part, timer.elapsed(), Status::Panicked, String::new()));
+    let reported = ChildResult {
+        status: result.status.as_str().to_string(),
+        answer: result.answer,
+        elapsed_ns: result.elapsed.as_nanos() as u64,
+    };
+    let line = serde_json::to_string(&reported).map_err(io::Error::other)?;
+    // the part may have printed without a trailing newline.
+    println!("\n{}{}", RESULT_PREFIX, line);
+    Ok(())
+}
+
+/// Runs and prints one part of a solution. This backs the `solve!` macro:
+/// when `--bench` is passed on the command line, the part is benchmarked instead of run once.
+pub fn solve<T: Display>(
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Option<T>,
+    input: &Input,
+) -> PartResult {
+    let options = match BenchOptions::from_env() {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    match options {
+        Some(options) => {
+            let mut bench = bench::bench_part(day, part, func, &input.text, &options);
+            bench.result.input = input.name.clone();
+            bench::print_bench(&bench);
+            bench.result
+        }
+        None => {
+            let mut result = run_part(day, part, func, &input.text);
+            result.input = input.name.clone();
+            print_result(&result);
+            result
+        }
+    }
+}
+
+pub fn print_result(result: &PartResult) {
+    println!("{}", result);
+}
+
+/// Sums the execution time of all solved parts.
+pub fn total_elapsed(results: &[PartResult]) -> Duration {
+    results
+        .iter()
+        .filter(|result| result.is_solved())
+        .map(|result| result.elapsed)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_part() {
+        let result = run_part(3, 1, |input| Some(input.len()), "hello");
+        assert_eq!(result.day, 3);
+        assert_eq!(result.part, 1);
+        assert_eq!(result.answer, "5");
+        assert!(result.is_solved());
+
+        let result = run_part(3, 2, |_| None::<u32>, "hello");
+        assert_eq!(result.answer, "");
+        assert_eq!(result.status, Status::Unsolved);
+    }
+
+    #[test]
+    fn test_run_isolated() {
+        let input: Arc<str> = Arc::from("hello");
+        let timeout = Some(Duration::from_millis(50));
+        let solution = Solution {
+            year: 2021,
+            day: 1,
+            part_one: |input| Some(input.len().to_string()),
+            part_two: |_| panic!("oh no"),
+        };
+
+        let result = run_isolated(&solution, 1, input.clone(), timeout);
+        assert_eq!(result.year, 2021);
+        assert_eq!(result.answer, "5");
+        assert!(result.is_solved());
+
+        let result = run_isolated(&solution, 2, input.clone(), timeout);
+        assert_eq!(result.status, Status::Panicked);
+        assert!(result.error.unwrap().contains("oh no"));
+
+        let solution = Solution {
+            year: DEFAULT_YEAR,
+            day: 2,
+            part_one: |_| {
+                thread::sleep(Duration::from_secs(2));
+                None
+            },
+            // a panic on another thread, like day 16's rayon workers.
+            part_two: |_| {
+                use rayon::prelude::*;
+                let sum: u32 = (0..64u32)
+                    .into_par_iter()
+                    .map(|i| if i == 42 { panic!("worker {}", i) } else { i })
+                    .sum();
+                Some(sum.to_string())
+            },
+        };
+        let result = run_isolated(&solution, 1, input.clone(), timeout);
+        assert_eq!(result.status, Status::TimedOut);
+        assert!(result.is_failure());
+
+        let result = run_isolated(&solution, 2, input, None);
+        assert_eq!(result.status, Status::Panicked);
+        assert!(result.error.unwrap().contains("worker 42"));
+    }
+
+    #[test]
+    fn test_child_result() {
+        // as printed by `serve_part`, after a part printed "debug".
+        let stdout = format!(
+            "debug\n\n{}{{\"status\":\"panicked\",\"answer\":\"\",\"elapsed_ns\":5}}\n",
+            RESULT_PREFIX
+        );
+        let result = child_result(4, 2, &stdout, "panicked at src/day04.rs:1:1:\noh no\n").unwrap();
+        assert_eq!(result.status, Status::Panicked);
+        assert_eq!(result.elapsed, Duration::from_nanos(5));
+        assert_eq!(
+            result.error.as_deref(),
+            Some("debug\npanicked at src/day04.rs:1:1:\noh no")
+        );
+
+        let stdout = format!(
+            "\n{}{{\"status\":\"solved\",\"answer\":\"42\",\"elapsed_ns\":7}}\n",
+            RESULT_PREFIX
+        );
+        let result = child_result(4, 1, &stdout, "").unwrap();
+        assert_eq!(result.answer, "42");
+        assert!(result.is_solved());
+        assert_eq!(result.error, None);
+
+        assert_eq!(child_result(4, 1, "killed\n", ""), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut result = PartResult {
+            year: DEFAULT_YEAR,
+            day: 1,
+            part: 2,
+            input: None,
+            answer: "42".to_string(),
+            elapsed: Duration::from_nanos(74),
+            status: Status::Solved,
+            error: None,
+        };
+        assert_eq!(
+            result.to_string(),
+            format!(
+                "🎄 {}Part 2{} 🎄\n42 {}(elapsed: 74.00ns){}",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
+        );
+
+        result.input = Some("alice".to_string());
+        assert_eq!(
+            result.header(),
+            format!("🎄 {}Part 2{} (alice) 🎄", ANSI_BOLD, ANSI_RESET)
+        );
+    }
+}
@@ -0,0 +1,637 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::input::relative_year_dir;
+use crate::puzzle::Example;
+use crate::DEFAULT_YEAR;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const BIN_TEMPLATE: &str = r###"use advent_of_code::solutions::MODULE::{part_one, part_two};
+
+fn main() {
+    for input in &advent_of_code::LOAD_INPUTS {
+        advent_of_code::solve!(DAY, 1, part_one, input);
+        advent_of_code::solve!(DAY, 2, part_two, input);
+    }
+}
+"###;
+
+const TESTS_MARKER: &str = "#[cfg(test)]";
+
+/// Where the files of a day go. Days of the default year keep the flat layout,
+/// days of other years live in a `yearYYYY` module and a `YYYY-DD` binary.
+pub struct Layout {
+    root: PathBuf,
+    pub year: u16,
+    pub day: u8,
+    pub day_padded: String,
+}
+
+impl Layout {
+    /// The layout of a day in the crate at `root`.
+    pub fn new(root: &Path, year: u16, day: u8) -> Self {
+        Self {
+            root: root.to_path_buf(),
+            year,
+            day,
+            day_padded: format!("{:02}", day),
+        }
+    }
+
+    fn is_default_year(&self) -> bool {
+        self.year == DEFAULT_YEAR
+    }
+
+    /// The module path below `solutions`, e.g. `day01` or `year2021::day01`.
+    pub fn module(&self) -> String {
+        if self.is_default_year() {
+            format!("day{}", self.day_padded)
+        } else {
+            format!("year{}::day{}", self.year, self.day_padded)
+        }
+    }
+
+    fn solutions_dir(&self) -> PathBuf {
+        self.root.join("src").join("solutions")
+    }
+
+    pub fn module_path(&self) -> PathBuf {
+        if self.is_default_year() {
+            self.solutions_dir()
+                .join(format!("day{}.rs", self.day_padded))
+        } else {
+            self.year_module_path()
+                .with_file_name(format!("day{}.rs", self.day_padded))
+        }
+    }
+
+    fn year_module_path(&self) -> PathBuf {
+        self.solutions_dir()
+            .join(format!("year{}", self.year))
+            .join("mod.rs")
+    }
+
+    pub fn registry_path(&self) -> PathBuf {
+        self.solutions_dir().join("mod.rs")
+    }
+
+    /// The name of the binary of the day, as passed to `cargo run --bin`.
+    pub fn bin(&self) -> String {
+        if self.is_default_year() {
+            self.day_padded.clone()
+        } else {
+            format!("{}-{}", self.year, self.day_padded)
+        }
+    }
+
+    pub fn bin_path(&self) -> PathBuf {
+        self.root
+            .join("src")
+            .join("bin")
+            .join(format!("{}.rs", self.bin()))
+    }
+
+    pub fn file_path(&self, folder: &str) -> PathBuf {
+        self.root
+            .join(relative_year_dir(self.year))
+            .join(folder)
+            .join(format!("{}.txt", self.day_padded))
+    }
+
+    fn read_example(&self) -> String {
+        if self.is_default_year() {
+            format!("crate::read_file(\"examples\", {})", self.day)
+        } else {
+            format!(
+                "crate::read_year_file(\"examples\", {}, {})",
+                self.year, self.day
+            )
+        }
+    }
+
+    fn load_inputs(&self) -> String {
+        if self.is_default_year() {
+            format!("load_inputs({})", self.day)
+        } else {
+            format!("load_year_inputs({}, {})", self.year, self.day)
+        }
+    }
+
+    /// The contents of the day's binary.
+    pub fn bin_contents(&self) -> String {
+        BIN_TEMPLATE
+            .replace("MODULE", &self.module())
+            .replace("LOAD_INPUTS", &self.load_inputs())
+            .replace("DAY", &self.day.to_string())
+    }
+}
+
+/// The return type and the test assertion for a part with the expected example answer.
+fn expected(answer: Option<&str>) -> (&'static str, String) {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => return ("u32", "None".to_string()),
+    };
+    if answer.parse::<u32>().is_ok() {
+        ("u32", format!("Some({})", answer))
+    } else if answer.parse::<u64>().is_ok() {
+        ("u64", format!("Some({})", answer))
+    } else if answer.parse::<i64>().is_ok() {
+        ("i64", format!("Some({})", answer))
+    } else {
+        ("String", format!("Some({:?}.to_string())", answer))
+    }
+}
+
+/// The values of the placeholders a template can use.
+pub fn template_values(layout: &Layout, example: &Example) -> HashMap<&'static str, String> {
+    let (type_one, expected_one) = expected(example.answers[0].as_deref());
+    let (type_two, expected_two) = expected(example.answers[1].as_deref());
+    HashMap::from([
+        ("day", layout.day.to_string()),
+        ("day_padded", layout.day_padded.clone()),
+        ("year", layout.year.to_string()),
+        ("part_one_type", type_one.to_string()),
+        ("part_two_type", type_two.to_string()),
+        ("part_one_expected", expected_one),
+        ("part_two_expected", expected_two),
+        ("read_example", layout.read_example()),
+    ])
+}
+
+/// Inserts `line` into the run of lines starting with `prefix`, keeping that run sorted by `key`.
+/// If there is no such line yet, `line` goes after the last line starting with `fallback`.
+fn insert_sorted<K: Ord>(
+    contents: &str,
+    prefix: &str,
+    fallback: &str,
+    line: &str,
+    key: impl Fn(&str) -> K,
+) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let index = match lines.iter().rposition(|l| l.starts_with(prefix)) {
+        Some(last) => lines
+            .iter()
+            .position(|l| l.starts_with(prefix) && key(l) > key(line))
+            .unwrap_or(last + 1),
+        None => lines.iter().rposition(|l| l.starts_with(fallback))? + 1,
+    };
+    lines.insert(index, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Sorts registry entries by year and day: `solution!(3, day03)` is a day of the default year,
+/// `solution!(2021, 1, year2021::day01)` one of another year.
+fn solution_key(line: &str) -> (u16, u8) {
+    let numbers: Vec<u16> = line
+        .trim_start()
+        .trim_start_matches("solution!(")
+        .split(", ")
+        .map_while(|field| field.parse().ok())
+        .collect();
+    match numbers[..] {
+        [year, day] => (year, day as u8),
+        [day] => (DEFAULT_YEAR, day as u8),
+        _ => (u16::MAX, 0),
+    }
+}
+
+/// What `cargo scaffold` does to a day that was scaffolded before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Refuse to touch an existing module or binary.
+    New,
+    /// Regenerate the module and binary, backing up the existing module.
+    Force,
+    /// Only add what's missing and never touch an existing solution.
+    AddMissing,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    Create(String),
+    /// Overwrites the file, copying it to `backup` first if set.
+    Update {
+        contents: String,
+        backup: Option<PathBuf>,
+    },
+    /// Leaves the file as it is, for the given reason.
+    Keep(&'static str),
+}
+
+/// A change to one file of a scaffolded day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// What the file is, e.g. `module` or `example`.
+    pub label: &'static str,
+    pub path: PathBuf,
+    pub action: Action,
+}
+
+impl Change {
+    fn new(label: &'static str, path: PathBuf, action: Action) -> Self {
+        Self {
+            label,
+            path,
+            action,
+        }
+    }
+
+    pub fn apply(&self) -> io::Result<()> {
+        let contents = match &self.action {
+            Action::Create(contents) => contents,
+            Action::Update { contents, backup } => {
+                if let Some(backup) = backup {
+                    fs::copy(&self.path, backup)?;
+                }
+                contents
+            }
+            Action::Keep(_) => return Ok(()),
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, contents)
+    }
+
+    /// A line describing the change, with paths relative to `root`.
+    /// With `dry_run`, it describes what would happen instead.
+    pub fn describe(&self, root: &Path, dry_run: bool) -> String {
+        let relative = |path: &Path| {
+            path.strip_prefix(root)
+                .unwrap_or(path)
+                .display()
+                .to_string()
+        };
+        let path = relative(&self.path);
+        match &self.action {
+            Action::Create(contents) => format!(
+                "{} {}{} file \"{}\"",
+                if dry_run { "Would create" } else { "Created" },
+                if contents.is_empty() { "empty " } else { "" },
+                self.label,
+                path
+            ),
+            Action::Update { backup, .. } => format!(
+                "{} {} file \"{}\"{}",
+                if dry_run { "Would update" } else { "Updated" },
+                self.label,
+                path,
+                backup.as_ref().map_or(String::new(), |backup| format!(
+                    " (backup: \"{}\")",
+                    relative(backup)
+                ))
+            ),
+            Action::Keep(reason) => format!(
+                "{} {} file \"{}\": {}",
+                if dry_run { "Would skip" } else { "Skipped" },
+                self.label,
+                path,
+                reason
+            ),
+        }
+    }
+}
+
+/// The first of `day01.rs.bak`, `day01.rs.1.bak`, ... that doesn't exist yet.
+fn backup_path(path: &Path) -> PathBuf {
+    (0..)
+        .map(|i| match i {
+            0 => path.with_extension("rs.bak"),
+            i => path.with_extension(format!("rs.{}.bak", i)),
+        })
+        .find(|backup| !backup.exists())
+        .unwrap()
+}
+
+fn already_exists(path: &Path) -> String {
+    format!(
+        "\"{}\" already exists. Pass --force to regenerate it or --add-missing to only add what's missing.",
+        path.display()
+    )
+}
+
+fn read(path: &Path) -> Result<Option<String>, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("could not read \"{}\": {}", path.display(), e)),
+    }
+}
+
+fn plan_module(layout: &Layout, mode: Mode, module: &str) -> Result<Change, String> {
+    let path = layout.module_path();
+    let action = match (read(&path)?, mode) {
+        (None, _) => Action::Create(module.to_string()),
+        (Some(_), Mode::New) => return Err(already_exists(&path)),
+        (Some(_), Mode::Force) => Action::Update {
+            contents: module.to_string(),
+            backup: Some(backup_path(&path)),
+        },
+        (Some(existing), Mode::AddMissing) if existing.contains(TESTS_MARKER) => {
+            Action::Keep("it already has tests")
+        }
+        (Some(existing), Mode::AddMissing) => match module.find(TESTS_MARKER) {
+            Some(start) => Action::Update {
+                contents: format!("{}\n\n{}", existing.trim_end(), &module[start..]),
+                backup: None,
+            },
+            None => Action::Keep("the template has no tests"),
+        },
+    };
+    Ok(Change::new("module", path, action))
+}
+
+fn plan_bin(layout: &Layout, mode: Mode) -> Result<Change, String> {
+    let path = layout.bin_path();
+    let action = match (path.exists(), mode) {
+        (false, _) => Action::Create(layout.bin_contents()),
+        (true, Mode::New) => return Err(already_exists(&path)),
+        (true, Mode::Force) => Action::Update {
+            contents: layout.bin_contents(),
+            backup: None,
+        },
+        (true, Mode::AddMissing) => Action::Keep("it already exists"),
+    };
+    Ok(Change::new("binary", path, action))
+}
+
+/// Adds `pub mod dayDD;` to the module file of a year, creating it if needed.
+fn plan_year_module(layout: &Layout) -> Result<Change, String> {
+    let path = layout.year_module_path();
+    let line = format!("pub mod day{};", layout.day_padded);
+    let action = match read(&path)? {
+        Some(contents) if contents.lines().any(|l| l == line) => {
+            Action::Keep("the day is already registered")
+        }
+        Some(contents) => Action::Update {
+            contents: insert_sorted(
+                &contents,
+                "pub mod day",
+                "pub mod day",
+                &line,
+                str::to_string,
+            )
+            .ok_or_else(|| format!("could not find the module list in \"{}\"", path.display()))?,
+            backup: None,
+        },
+        None => Action::Create(format!("{}\n", line)),
+    };
+    Ok(Change::new("year module", path, action))
+}
+
+fn plan_registry(layout: &Layout) -> Result<Change, String> {
+    let path = layout.registry_path();
+    let contents = read(&path)?.ok_or_else(|| format!("could not find \"{}\"", path.display()))?;
+    let module = layout.module();
+    let entry = if layout.is_default_year() {
+        format!("    solution!({}, {}),", layout.day, module)
+    } else {
+        format!(
+            "    solution!({}, {}, {}),",
+            layout.year, layout.day, module
+        )
+    };
+    if contents.contains(&entry) {
+        return Ok(Change::new(
+            "registry",
+            path,
+            Action::Keep("the day is already registered"),
+        ));
+    }
+
+    let line = if layout.is_default_year() {
+        format!("pub mod {};", module)
+    } else {
+        format!("pub mod year{};", layout.year)
+    };
+    let contents = if contents.lines().any(|l| l == line) {
+        Some(contents)
+    } else if layout.is_default_year() {
+        insert_sorted(
+            &contents,
+            "pub mod day",
+            "pub mod day",
+            &line,
+            str::to_string,
+        )
+    } else {
+        insert_sorted(
+            &contents,
+            "pub mod year",
+            "pub mod day",
+            &line,
+            str::to_string,
+        )
+    }
+    .and_then(|contents| {
+        insert_sorted(
+            &contents,
+            "    solution!(",
+            "    solution!(",
+            &entry,
+            solution_key,
+        )
+    })
+    .ok_or_else(|| "could not find the module list or the solution registry".to_string())?;
+
+    Ok(Change::new(
+        "registry",
+        path,
+        Action::Update {
+            contents,
+            backup: None,
+        },
+    ))
+}
+
+fn plan_example(layout: &Layout, example: &Example) -> Result<Change, String> {
+    let path = layout.file_path("examples");
+    let input = example.input.clone().unwrap_or_default();
+    let action = match read(&path)? {
+        None => Action::Create(input),
+        // an existing example is never overwritten.
+        Some(existing) if existing.is_empty() && !input.is_empty() => Action::Update {
+            contents: input,
+            backup: None,
+        },
+        Some(_) => Action::Keep("it already exists"),
+    };
+    Ok(Change::new("example", path, action))
+}
+
+/// Plans the changes that scaffold a day with the rendered solution `module`.
+/// Nothing is written until the changes are applied, so a plan can be shown as a dry run.
+pub fn plan(
+    layout: &Layout,
+    mode: Mode,
+    module: &str,
+    example: &Example,
+) -> Result<Vec<Change>, String> {
+    let mut changes = vec![plan_module(layout, mode, module)?, plan_bin(layout, mode)?];
+    if !layout.is_default_year() {
+        changes.push(plan_year_module(layout)?);
+    }
+    changes.push(plan_registry(layout)?);
+
+    let input_path = layout.file_path("inputs");
+    changes.push(if input_path.exists() {
+        Change::new("input", input_path, Action::Keep("it already exists"))
+    } else {
+        Change::new("input", input_path, Action::Create(String::new()))
+    });
+    changes.push(plan_example(layout, example)?);
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const MODULE: &str = "pub fn part_one(input: &str) -> Option<u32> {\n    None\n}\n\n#[cfg(test)]\nmod tests {}\n";
+
+    /// A crate in a temporary directory with a registry of days 3 and 10.
+    fn setup(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src").join("solutions")).unwrap();
+        fs::write(
+            root.join("src").join("solutions").join("mod.rs"),
+            "pub mod day03;\npub mod day10;\n\npub static SOLUTIONS: &[Solution] = &[\n    solution!(3, day03),\n    solution!(10, day10),\n];\n",
+        )
+        .unwrap();
+        root
+    }
+
+    fn apply(changes: &[Change]) {
+        for change in changes {
+            change.apply().unwrap();
+        }
+    }
+
+    #[test]
+    fn test_expected() {
+        assert_eq!(expected(None), ("u32", "None".to_string()));
+        assert_eq!(expected(Some("6000")), ("u32", "Some(6000)".to_string()));
+        assert_eq!(expected(Some("-3")), ("i64", "Some(-3)".to_string()));
+        assert_eq!(
+            expected(Some("CMZ")),
+            ("String", "Some(\"CMZ\".to_string())".to_string())
+        );
+    }
+
+    #[test]
+    fn test_new() {
+        let root = setup("new");
+        let layout = Layout::new(&root, DEFAULT_YEAR, 4);
+        let changes = plan(&layout, Mode::New, MODULE, &Example::default()).unwrap();
+        assert!(!layout.module_path().exists());
+        assert_eq!(
+            changes[0].describe(&root, true),
+            "Would create module file \"src/solutions/day04.rs\""
+        );
+        assert_eq!(
+            changes[4].describe(&root, false),
+            "Created empty example file \"src/examples/04.txt\""
+        );
+
+        apply(&changes);
+        assert_eq!(fs::read_to_string(layout.module_path()).unwrap(), MODULE);
+        assert_eq!(
+            fs::read_to_string(layout.registry_path()).unwrap(),
+            "pub mod day03;\npub mod day04;\npub mod day10;\n\npub static SOLUTIONS: &[Solution] = &[\n    solution!(3, day03),\n    solution!(4, day04),\n    solution!(10, day10),\n];\n"
+        );
+        assert!(plan(&layout, Mode::New, MODULE, &Example::default())
+            .unwrap_err()
+            .contains("already exists"));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_other_year() {
+        let root = setup("year");
+        let layout = Layout::new(&root, 2021, 1);
+        apply(&plan(&layout, Mode::New, MODULE, &Example::default()).unwrap());
+        assert!(root.join("src/solutions/year2021/day01.rs").exists());
+        assert!(root.join("src/bin/2021-01.rs").exists());
+        assert!(root.join("src/2021/inputs/01.txt").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("src/solutions/year2021/mod.rs")).unwrap(),
+            "pub mod day01;\n"
+        );
+        let registry = fs::read_to_string(layout.registry_path()).unwrap();
+        assert!(registry.contains("pub mod day10;\npub mod year2021;\n"));
+        assert!(
+            registry.contains("    solution!(2021, 1, year2021::day01),\n    solution!(3, day03),")
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_force() {
+        let root = setup("force");
+        let layout = Layout::new(&root, DEFAULT_YEAR, 4);
+        apply(&plan(&layout, Mode::New, MODULE, &Example::default()).unwrap());
+        fs::write(layout.module_path(), "// my solution\n").unwrap();
+
+        let changes = plan(&layout, Mode::Force, MODULE, &Example::default()).unwrap();
+        assert_eq!(
+            changes[0].describe(&root, false),
+            "Updated module file \"src/solutions/day04.rs\" (backup: \"src/solutions/day04.rs.bak\")"
+        );
+        assert_eq!(
+            changes[2].action,
+            Action::Keep("the day is already registered")
+        );
+        apply(&changes);
+        assert_eq!(fs::read_to_string(layout.module_path()).unwrap(), MODULE);
+        assert_eq!(
+            fs::read_to_string(root.join("src/solutions/day04.rs.bak")).unwrap(),
+            "// my solution\n"
+        );
+        assert_eq!(
+            backup_path(&layout.module_path()),
+            root.join("src/solutions/day04.rs.1.bak")
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn test_add_missing() {
+        let root = setup("missing");
+        let layout = Layout::new(&root, DEFAULT_YEAR, 4);
+        apply(&plan(&layout, Mode::New, MODULE, &Example::default()).unwrap());
+        fs::write(layout.module_path(), "// my solution\n").unwrap();
+
+        let example = Example {
+            input: Some("1\n2\n".to_string()),
+            answers: [None, None],
+        };
+        let changes = plan(&layout, Mode::AddMissing, MODULE, &example).unwrap();
+        assert_eq!(changes[1].action, Action::Keep("it already exists"));
+        apply(&changes);
+        assert_eq!(
+            fs::read_to_string(layout.module_path()).unwrap(),
+            "// my solution\n\n#[cfg(test)]\nmod tests {}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(layout.file_path("examples")).unwrap(),
+            "1\n2\n"
+        );
+
+        let changes = plan(&layout, Mode::AddMissing, MODULE, &example).unwrap();
+        assert!(changes
+            .iter()
+            .all(|change| matches!(change.action, Action::Keep(_))));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
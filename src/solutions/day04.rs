@@ -0,0 +1,73 @@
+use regex::Regex;
+
+use crate::helpers::IntervalSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let lines = parse_input(input);
+    Some(lines.iter().filter(|line| line.is_full_overlap()).count() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let lines = parse_input(input);
+    Some(lines.iter().filter(|line| line.is_overlap()).count() as u32)
+}
+
+#[derive(Debug)]
+struct PairRange {
+    pub first: IntervalSet,
+    pub second: IntervalSet,
+}
+
+impl PairRange {
+    fn is_full_overlap(&self) -> bool {
+        let common = self.first.intersection(&self.second);
+        common == self.first || common == self.second
+    }
+
+    fn is_overlap(&self) -> bool {
+        !self.first.intersection(&self.second).is_empty()
+    }
+}
+
+const REGEX: &str = r"(?x)(?P<left1>\d+)-(?P<right1>\d+),(?P<left2>\d+)-(?P<right2>\d+)";
+
+fn parse_input(input: &str) -> Vec<PairRange> {
+    let re = Regex::new(REGEX).unwrap();
+
+    re.captures_iter(input)
+        .map(|cap| {
+            let range = |left: &str, right: &str| {
+                IntervalSet::from(cap[left].parse::<i64>().unwrap()..=cap[right].parse().unwrap())
+            };
+            PairRange {
+                first: range("left1", "right1"),
+                second: range("left2", "right2"),
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_one(&input), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 4);
+        assert_eq!(part_two(&input), Some(4));
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = String::from("2-4,6-8");
+        let res = parse_input(&input);
+        assert_eq!(res.len(), 1);
+        assert_eq!(res[0].first, IntervalSet::from(2..=4));
+    }
+}
@@ -0,0 +1,86 @@
+use crate::helpers::Grid;
+use std::cmp;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let grid = parse_input(input);
+    let (m, n) = (grid.height(), grid.width());
+
+    let mut heighest_bottom_right = Grid::filled(n, m, (0, 0));
+    for i in (1..m - 1).rev() {
+        for j in (1..n - 1).rev() {
+            heighest_bottom_right[(i, j)].0 =
+                cmp::max(heighest_bottom_right[(i + 1, j)].0, grid[(i + 1, j)]);
+            heighest_bottom_right[(i, j)].1 =
+                cmp::max(heighest_bottom_right[(i, j + 1)].1, grid[(i, j + 1)]);
+        }
+    }
+    let mut heighest_top_left = Grid::filled(n, m, (0, 0));
+    let mut ans = 2 * (m + n) as u32 - 4;
+    for i in 1..m - 1 {
+        for j in 1..n - 1 {
+            heighest_top_left[(i, j)].0 =
+                cmp::max(heighest_top_left[(i - 1, j)].0, grid[(i - 1, j)]);
+            heighest_top_left[(i, j)].1 =
+                cmp::max(heighest_top_left[(i, j - 1)].1, grid[(i, j - 1)]);
+            let cur = grid[(i, j)];
+            if cur > heighest_top_left[(i, j)].0
+                || cur > heighest_top_left[(i, j)].1
+                || cur > heighest_bottom_right[(i, j)].0
+                || cur > heighest_bottom_right[(i, j)].1
+            {
+                ans += 1;
+            }
+        }
+    }
+    Some(ans)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let grid = parse_input(input);
+    let (m, n) = (grid.height(), grid.width());
+    let mut ans = 0;
+    for i in 1..m - 1 {
+        for j in 1..n - 1 {
+            let down = get_distance(&grid, (i, j), (1, 0));
+            let up = get_distance(&grid, (i, j), (-1, 0));
+            let left = get_distance(&grid, (i, j), (0, -1));
+            let right = get_distance(&grid, (i, j), (0, 1));
+            ans = ans.max(left * right * up * down);
+        }
+    }
+    Some(ans)
+}
+
+/// The number of trees visible from `pos` in `direction`, up to the first that is at least as tall.
+fn get_distance(grid: &Grid<u32>, pos: (usize, usize), direction: (isize, isize)) -> u32 {
+    let cur = grid[pos];
+    let mut ans = 0;
+    for next in grid.ray(pos, direction) {
+        ans += 1;
+        if grid[next] >= cur {
+            break;
+        }
+    }
+    ans
+}
+
+fn parse_input(input: &str) -> Grid<u32> {
+    Grid::parse(input, |c| c.to_digit(10).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_one(&input), Some(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 8);
+        assert_eq!(part_two(&input), Some(8));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::helpers::{Direction, Point2};
+use std::collections::HashSet;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let commands = parse_input(input);
+    let mut set = HashSet::new();
+    let mut rope = Rope::new(1);
+    for command in commands {
+        for _ in 0..command.steps {
+            set.insert(rope.step(command.direction));
+        }
+    }
+    Some(set.len() as u32)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let commands = parse_input(input);
+    let mut set = HashSet::new();
+    let mut rope = Rope::new(9);
+    for command in commands {
+        for _ in 0..command.steps {
+            set.insert(rope.step(command.direction));
+        }
+    }
+    Some(set.len() as u32)
+}
+
+#[derive(Debug)]
+struct Rope {
+    pub knots: Vec<Point2>,
+}
+
+impl Rope {
+    pub fn new(nums: u32) -> Self {
+        Self {
+            knots: vec![Point2::ORIGIN; nums as usize + 1],
+        }
+    }
+
+    /// Moves the head one step and returns where the tail ends up.
+    fn step(&mut self, direction: Direction) -> Point2 {
+        self.knots[0] += direction.offset();
+        for i in 1..self.knots.len() {
+            self.step_tail(i);
+        }
+        *self.knots.last().unwrap()
+    }
+
+    fn step_tail(&mut self, index: usize) {
+        let (head, tail) = (self.knots[index - 1], self.knots[index]);
+        if head.chebyshev(tail) > 1 {
+            self.knots[index] += (head - tail).signum();
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Command> {
+    input.lines().map(Command::from).collect()
+}
+
+#[derive(Debug)]
+struct Command {
+    pub direction: Direction,
+    pub steps: u32,
+}
+
+impl From<&str> for Command {
+    fn from(s: &str) -> Self {
+        let (direction, steps) = s.split_once(' ').unwrap();
+        let direction = Direction::try_from(direction.chars().next().unwrap()).unwrap();
+        let steps = steps.parse::<u32>().unwrap();
+        Self { direction, steps }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 9);
+        assert_eq!(part_one(&input), Some(88));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 9);
+        assert_eq!(part_two(&input), Some(36));
+    }
+}
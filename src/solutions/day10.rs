@@ -0,0 +1,191 @@
+pub fn part_one(input: &str) -> Option<i32> {
+    let instructions = parse_input(input);
+    let mut sys = Sys::new();
+    for instruction in instructions {
+        sys.step(instruction);
+    }
+    Some(sys.signal_strengths)
+}
+
+/// The letters drawn on the CRT. A drawing that is not made of letters, like the example's,
+/// is returned as is, one line per row.
+pub fn part_two(input: &str) -> Option<String> {
+    let instructions = parse_input(input);
+    let mut sys = Sys::new();
+    for instruction in instructions {
+        sys.step(instruction);
+    }
+    Some(read_letters(&sys.screen).unwrap_or_else(|| sys.screen.join("\n")))
+}
+
+/// The rows of the letters the CRT can draw, each 4 pixels wide and followed by a blank column.
+const LETTERS: [(char, [&str; 6]); 16] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Reads the 8 letters on the screen, `None` if any of them is not a known letter.
+fn read_letters(screen: &[String]) -> Option<String> {
+    if screen.len() != 6 {
+        return None;
+    }
+    (0..40)
+        .step_by(5)
+        .map(|col| {
+            LETTERS.iter().find_map(|(letter, rows)| {
+                screen
+                    .iter()
+                    .zip(rows)
+                    .all(|(line, row)| line.get(col..col + 4) == Some(*row))
+                    .then_some(*letter)
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Sys {
+    x: i32,
+    pub cycle: u32,
+    pub signal_strengths: i32,
+    crt_row: String,
+    screen: Vec<String>,
+}
+
+impl Sys {
+    pub fn new() -> Self {
+        Self {
+            x: 1,
+            cycle: 0,
+            signal_strengths: 0,
+            crt_row: String::new(),
+            screen: Vec::new(),
+        }
+    }
+
+    pub fn step(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Add(arg) => {
+                self.tick();
+                self.tick();
+                self.x += arg;
+            }
+            Instruction::Noop => {
+                self.tick();
+            }
+        }
+    }
+
+    fn tick(&mut self) {
+        self.cycle += 1;
+        self.calculate_strength();
+        self.draw_crt();
+    }
+
+    fn calculate_strength(&mut self) {
+        if self.is_cycles() {
+            self.signal_strengths += self.x * self.cycle as i32;
+        }
+    }
+
+    fn is_cycles(&self) -> bool {
+        self.cycle == 20 || (self.cycle > 20 && (self.cycle - 20).is_multiple_of(40))
+    }
+
+    fn draw_crt(&mut self) {
+        let cur_pos = self.crt_row.len() as i32;
+        if cur_pos.abs_diff(self.x) <= 1 {
+            self.crt_row.push('#');
+        } else {
+            self.crt_row.push('.');
+        }
+        if (cur_pos + 1) % 40 == 0 {
+            self.screen.push(std::mem::take(&mut self.crt_row));
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Instruction {
+    Add(i32),
+    Noop,
+}
+
+fn parse_input(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let op = parts.next().unwrap();
+            match op {
+                "noop" => Instruction::Noop,
+                "addx" => {
+                    let arg = parts.next().unwrap().parse().unwrap();
+                    Instruction::Add(arg)
+                }
+                _ => panic!("Unknown instruction"),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 10);
+        assert_eq!(part_one(&input), Some(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 10);
+        let screen = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(part_two(&input).as_deref(), Some(screen));
+    }
+
+    #[test]
+    fn test_read_letters() {
+        let screen: Vec<String> = (0..6)
+            .map(|row| {
+                "ZEHLRAJU"
+                    .chars()
+                    .map(|c| {
+                        let (_, rows) = LETTERS.iter().find(|(letter, _)| *letter == c).unwrap();
+                        format!("{}.", rows[row])
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(read_letters(&screen).as_deref(), Some("ZEHLRAJU"));
+
+        // a drawing that is not made of letters, like the example's, is not read.
+        let input = crate::read_file("examples", 10);
+        let mut sys = Sys::new();
+        for instruction in parse_input(&input) {
+            sys.step(instruction);
+        }
+        assert_eq!(read_letters(&sys.screen), None);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::helpers::grid::{Grid, Position};
+use crate::helpers::search;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let climbing = Climbing::from(input);
+    climb(&climbing.hill, [climbing.start], climbing.end)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let climbing = Climbing::from(input);
+    let starts = climbing
+        .hill
+        .iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(pos, _)| pos);
+    climb(&climbing.hill, starts, climbing.end)
+}
+
+/// The fewest steps from the closest of `starts` to `end`, climbing at most one up per step.
+fn climb(
+    hill: &Grid<u32>,
+    starts: impl IntoIterator<Item = Position>,
+    end: Position,
+) -> Option<u32> {
+    let path = search::bfs(
+        starts,
+        |&pos| {
+            hill.neighbours(pos)
+                .filter(move |&next| hill[next] <= hill[pos] + 1)
+        },
+        |&pos| pos == end,
+    )?;
+    Some(path.len() as u32 - 1)
+}
+
+#[derive(Debug)]
+struct Climbing {
+    start: Position,
+    end: Position,
+    hill: Grid<u32>,
+}
+
+impl From<&str> for Climbing {
+    fn from(input: &str) -> Self {
+        let grid = Grid::parse(input, |c| c);
+        let start = grid.position(|&c| c == 'S').unwrap_or_default();
+        let end = grid.position(|&c| c == 'E').unwrap_or_default();
+        let hill = grid.map(|&c| match c {
+            'S' => 0,
+            'E' => 25,
+            c => c as u32 - 'a' as u32,
+        });
+        Self { start, end, hill }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_one(&input), Some(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 12);
+        assert_eq!(part_two(&input), Some(29));
+    }
+}
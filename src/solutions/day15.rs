@@ -0,0 +1,109 @@
+use lazy_static::lazy_static;
+use std::collections::HashSet;
+
+use crate::helpers::IntervalSet;
+use crate::params::Params;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (params, input) = Params::split(input);
+    let pairs = parse_input(input);
+    Some(count_unavailable_positions(
+        &pairs,
+        params.get_or("row", 2000000),
+    ))
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (params, input) = Params::split(input);
+    let pairs = parse_input(input);
+    let (x, y) = find_available_position(&pairs, params.get_or("limit", 4000000));
+    Some(x as u64 * 4000000 + y as u64)
+}
+
+lazy_static!(
+    static ref RE: regex::Regex = regex::Regex::new(r"(?x)Sensor\sat\sx=(?P<sensor_x>-?\d+),\sy=(?P<sensor_y>-?\d+):\sclosest\sbeacon\sis\sat\sx=(?P<beacon_x>-?\d+),\sy=(?P<beacon_y>-?\d+)").unwrap();
+);
+
+/// The positions on row `y` that are covered by a sensor, clamped to `bounds` if given.
+fn coverage(pairs: &[SensorBeaconPair], y: i32, bounds: Option<(i32, i32)>) -> IntervalSet {
+    pairs
+        .iter()
+        .filter_map(|pair| {
+            let diff = pair.distance.checked_sub(pair.sensor_y.abs_diff(y))? as i32;
+            let (mut start, mut end) = (pair.sensor_x - diff, pair.sensor_x + diff);
+            if let Some((min, max)) = bounds {
+                (start, end) = (start.max(min), end.min(max));
+            }
+            Some(start as i64..=end as i64)
+        })
+        .collect()
+}
+
+fn count_unavailable_positions(pairs: &[SensorBeaconPair], y: i32) -> u32 {
+    let covered = coverage(pairs, y, None);
+    let beacons: HashSet<i32> = pairs
+        .iter()
+        .filter(|pair| pair.beacon_y == y)
+        .map(|pair| pair.beacon_x)
+        .collect();
+    // beacons are always covered by the sensor they are closest to.
+    (covered.len() - beacons.len() as u64) as u32
+}
+
+fn find_available_position(pairs: &[SensorBeaconPair], limit: i32) -> (i32, i32) {
+    for y in 0..=limit {
+        let covered = coverage(pairs, y, Some((0, limit)));
+        if let Some(gap) = covered.gaps(0..=limit as i64).first() {
+            return (*gap.start() as i32, y);
+        }
+    }
+    unreachable!()
+}
+
+#[derive(Debug)]
+struct SensorBeaconPair {
+    sensor_x: i32,
+    sensor_y: i32,
+    beacon_x: i32,
+    beacon_y: i32,
+    distance: u32,
+}
+
+impl From<&str> for SensorBeaconPair {
+    fn from(s: &str) -> Self {
+        let caps = RE.captures(s).unwrap();
+        let sensor_x = caps["sensor_x"].parse::<i32>().unwrap();
+        let sensor_y = caps["sensor_y"].parse::<i32>().unwrap();
+        let beacon_x = caps["beacon_x"].parse::<i32>().unwrap();
+        let beacon_y = caps["beacon_y"].parse::<i32>().unwrap();
+        let distance = sensor_x.abs_diff(beacon_x) + sensor_y.abs_diff(beacon_y);
+        Self {
+            sensor_x,
+            sensor_y,
+            beacon_x,
+            beacon_y,
+            distance,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<SensorBeaconPair> {
+    input.lines().map(SensorBeaconPair::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_one(&input), Some(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 15);
+        assert_eq!(part_two(&input), Some(56000011));
+    }
+}
@@ -0,0 +1,195 @@
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+
+use rayon::prelude::*;
+
+use crate::helpers::search;
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let valve_map = build_valves(input);
+    Some(max_pressure(&valve_map, 30))
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let valve_map = build_valves(input);
+    let nodes: BTreeSet<&str> = valve_map
+        .keys()
+        .filter(|name| name != &"AA")
+        .map(|name| name.as_ref())
+        .collect();
+    let powerset_paths: Vec<BTreeSet<&str>> = nodes
+        .clone()
+        .into_iter()
+        .powerset()
+        .map(|set| set.into_iter().collect::<BTreeSet<_>>())
+        .collect_vec();
+
+    let ans = powerset_paths
+        .into_par_iter()
+        .map(|path| {
+            let diff = nodes.difference(&path).copied().collect::<BTreeSet<_>>();
+            // println! {"path: {:?}, diff: {:?}", path, diff};
+            let human = build_path_valves(&path, &valve_map);
+            let elephant = build_path_valves(&diff, &valve_map);
+            let human_max = max_pressure(&human, 26);
+            let elephant_max = max_pressure(&elephant, 26);
+            human_max + elephant_max
+        })
+        .max()
+        .unwrap();
+
+    // let mut cache = HashMap::new();
+    // let mut ans = 0;
+    // for path in powerset_paths {
+    //     let diff = nodes.difference(&path).copied().collect::<BTreeSet<_>>();
+    //     println!{"path: {:?}, diff: {:?}", path, diff};
+    //     let res = if cache.contains_key(&path) {
+    //         cache.get(&path).unwrap() + cache.get(&diff).unwrap()
+    //     } else {
+    //         let human = build_path_valves(&path, &valve_map);
+    //         let elephant = build_path_valves(&diff, &valve_map);
+    //         let human_max = max_pressure(&human, 26);
+    //         let elephant_max = max_pressure(&elephant, 26);
+    //         cache.insert(path, human_max);
+    //         cache.insert(diff, elephant_max);
+    //         human_max + elephant_max
+    //     };
+    //     ans = ans.max(res);
+    // }
+
+    Some(ans)
+}
+
+fn build_path_valves(
+    path: &BTreeSet<&str>,
+    valves: &HashMap<String, Valve>,
+) -> HashMap<String, Valve> {
+    let mut res = path
+        .iter()
+        .map(|&name| (name.to_string(), valves[name].clone()))
+        .collect::<HashMap<_, _>>();
+    res.insert("AA".to_string(), valves["AA"].clone());
+    res
+}
+
+fn max_pressure(valves: &HashMap<String, Valve>, time: u32) -> u32 {
+    let mut ans = 0;
+    let mut queue: VecDeque<(&str, u32, u32, BTreeSet<&str>)> = VecDeque::new();
+    queue.push_back(("AA", time, 0, BTreeSet::new()));
+
+    let mut cache = HashMap::new();
+    while let Some((name, time, pressure, visited)) = queue.pop_front() {
+        let prev = cache
+            .get(&(name, time, visited.clone()))
+            .copied()
+            .unwrap_or(0);
+        if prev > pressure {
+            continue;
+        }
+        cache.insert((name, time, visited.clone()), pressure);
+        ans = ans.max(pressure);
+
+        let valve = valves.get(name).unwrap();
+
+        for (next, dist) in valve.neighbors.iter() {
+            if time <= dist + 1 {
+                continue;
+            }
+            let minute = time - dist - 1;
+            if !visited.contains(&next.as_ref()) && valves.contains_key(next) {
+                let mut visited = visited.clone();
+                visited.insert(next);
+                queue.push_back((
+                    next,
+                    minute,
+                    pressure + valves[next].flow_rate * minute,
+                    visited,
+                ));
+            }
+        }
+    }
+    ans
+}
+
+fn build_valves(input: &str) -> HashMap<String, Valve> {
+    let valves = parse_input(input);
+    let names: Vec<String> = valves.iter().map(|valve| valve.name.clone()).collect();
+    let tunnels: HashMap<String, Vec<String>> = valves
+        .iter()
+        .map(|valve| (valve.name.clone(), valve.tunnels.clone()))
+        .collect();
+    let distances = search::floyd_warshall(&names, |name| {
+        tunnels[name].iter().map(|next| (next.clone(), 1))
+    });
+
+    // only keep the valves worth opening, and where we start.
+    let mut valves_map: HashMap<String, Valve> = valves
+        .into_iter()
+        .filter(|valve| valve.name == "AA" || valve.flow_rate > 0)
+        .map(|valve| (valve.name.clone(), valve))
+        .collect();
+    let keys = valves_map.keys().cloned().collect::<HashSet<_>>();
+    for (name, valve) in valves_map.iter_mut() {
+        valve.neighbors = keys
+            .iter()
+            .filter(|next| *next != name)
+            .filter_map(|next| {
+                let distance = distances.get(&(name.clone(), next.clone()))?;
+                Some((next.clone(), *distance as u32))
+            })
+            .collect();
+    }
+    valves_map
+}
+
+lazy_static!(
+    static ref RE: regex::Regex = regex::Regex::new(r"Valve (?P<source>\w+) has flow rate=(?P<rate>\d+); tunnels? leads? to valves? (?P<target>[\w+|,| ]+)").unwrap();
+);
+
+#[derive(Debug, Clone)]
+struct Valve {
+    name: String,
+    flow_rate: u32,
+    tunnels: Vec<String>,
+    neighbors: HashMap<String, u32>,
+}
+
+fn parse_input(input: &str) -> Vec<Valve> {
+    let mut valves = Vec::new();
+    for line in input.lines() {
+        let captures = RE.captures(line).unwrap();
+        let name = captures["source"].to_string();
+        let flow_rate = captures["rate"].parse::<u32>().unwrap();
+        let mut tunnels = Vec::new();
+        for tunnel in captures["target"].split(", ") {
+            tunnels.push(tunnel.to_string());
+        }
+        valves.push(Valve {
+            name: name.to_string(),
+            flow_rate,
+            tunnels,
+            neighbors: HashMap::new(),
+        });
+    }
+
+    valves
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 16);
+        assert_eq!(part_one(&input), Some(1651));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 16);
+        assert_eq!(part_two(&input), Some(1707));
+    }
+}
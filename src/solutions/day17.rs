@@ -0,0 +1,181 @@
+use crate::helpers::{cycle, Direction, Point2};
+use crate::params::Params;
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let (params, input) = Params::split(input);
+    let jet = Jet::from(input);
+    let mut chamber = Chamber::new(jet);
+    let rocks = rocks();
+
+    for i in 0..params.get_or("part_one_rocks", 2022) {
+        chamber.fall_rock(&rocks[i % rocks.len()]);
+    }
+
+    Some(chamber.cur_height as u64)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (params, input) = Params::split(input);
+    let jet = Jet::from(input);
+    let mut chamber = Chamber::new(jet);
+    let rocks = rocks();
+    // the chamber repeats once the next rock and jet and the top of the tower are the same.
+    let height = cycle::extrapolate(
+        &mut chamber,
+        params.get_or("part_two_rocks", 1_000_000_000_000),
+        |chamber| chamber.fall_rock(&rocks[chamber.fallen % rocks.len()]),
+        |chamber| {
+            (
+                chamber.fallen % rocks.len(),
+                chamber.jet.cur,
+                chamber.snapshot(),
+            )
+        },
+        |chamber| chamber.cur_height,
+    );
+    Some(height as u64)
+}
+
+/// The blocks of a rock relative to its bottom left corner, with `x` to the right and `y` up.
+#[derive(Debug)]
+struct Rock {
+    pub blocks: Vec<Point2>,
+}
+
+impl Rock {
+    fn new(blocks: &[(i64, i64)]) -> Self {
+        Self {
+            blocks: blocks.iter().map(|&(x, y)| Point2::new(x, y)).collect(),
+        }
+    }
+}
+
+fn rocks() -> Vec<Rock> {
+    vec![
+        Rock::new(&[(0, 0), (1, 0), (2, 0), (3, 0)]),
+        Rock::new(&[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)]),
+        Rock::new(&[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]),
+        Rock::new(&[(0, 0), (0, 1), (0, 2), (0, 3)]),
+        Rock::new(&[(0, 0), (1, 0), (0, 1), (1, 1)]),
+    ]
+}
+
+#[derive(Debug)]
+struct Chamber {
+    grid: Vec<Vec<char>>,
+    cur_height: i64,
+    /// The number of rocks that came to rest.
+    fallen: usize,
+    jet: Jet,
+}
+
+impl Chamber {
+    fn new(jet: Jet) -> Self {
+        Self {
+            grid: vec![vec!['.'; 7]; 30000],
+            cur_height: 0,
+            fallen: 0,
+            jet,
+        }
+    }
+
+    fn snapshot(&self) -> String {
+        let mut s = String::new();
+        for row in (self.cur_height - 30).max(0)..self.cur_height {
+            for col in 0..7 {
+                s.push(self.grid[row as usize][col]);
+            }
+        }
+        s
+    }
+
+    fn fall_rock(&mut self, rock: &Rock) {
+        let mut pos = Point2::new(2, self.cur_height + 3);
+        // `y` is the height in the chamber, so falling lowers it.
+        let down = Point2::new(0, -1);
+        loop {
+            let jet = self.jet.next().offset();
+            if self.can_move(rock, pos + jet) {
+                pos += jet;
+            }
+            if self.can_move(rock, pos + down) {
+                pos += down;
+            } else {
+                // can not move
+                self.integrate_rock(rock, pos);
+                break;
+            }
+        }
+    }
+
+    fn can_move(&self, rock: &Rock, pos: Point2) -> bool {
+        rock.blocks.iter().all(|&block| {
+            let Point2 { x, y } = pos + block;
+            (0..7).contains(&x) && y >= 0 && self.grid[y as usize][x as usize] != '#'
+        })
+    }
+
+    fn integrate_rock(&mut self, rock: &Rock, pos: Point2) {
+        for &block in rock.blocks.iter() {
+            let Point2 { x, y } = pos + block;
+            self.grid[y as usize][x as usize] = '#';
+            self.cur_height = self.cur_height.max(y + 1);
+        }
+        self.fallen += 1;
+    }
+}
+
+#[derive(Debug)]
+struct Jet {
+    data: Vec<Direction>,
+    cur: usize,
+}
+
+impl From<&str> for Jet {
+    fn from(s: &str) -> Self {
+        let data = s
+            .trim_end()
+            .chars()
+            .map(|c| Direction::try_from(c).unwrap())
+            .collect();
+        Self { data, cur: 0 }
+    }
+}
+
+impl Jet {
+    fn next(&mut self) -> Direction {
+        let c = self.data[self.cur];
+        self.cur += 1;
+        self.cur %= self.data.len();
+        c
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 17);
+        assert_eq!(part_one(&input), Some(3068));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 17);
+        assert_eq!(part_two(&input), Some(1514285714288));
+    }
+
+    #[test]
+    fn test_rocks() {
+        let input = crate::read_file("examples", 17);
+        for (rocks, height) in [(1, 1), (2, 4), (3, 6), (10, 17)] {
+            let input = format!("#! part_one_rocks = {}\n{}", rocks, input);
+            assert_eq!(part_one(&input), Some(height), "after {} rocks", rocks);
+        }
+        // the extrapolated height matches the simulated one.
+        let input = format!("#! part_two_rocks = 2022\n{}", input);
+        assert_eq!(part_two(&input), Some(3068));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::helpers::Point3;
+use std::collections::{HashSet, VecDeque};
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let cubes = parse_input(input);
+    let mut total = 6 * cubes.len() as u32;
+    for i in 0..cubes.len() {
+        for j in i..cubes.len() {
+            if cubes[i].manhattan(cubes[j]) == 1 {
+                total -= 2;
+            }
+        }
+    }
+    Some(total)
+}
+pub fn minimum_size(nums: Vec<i32>, max_operations: i32) -> i32 {
+    let (mut left, mut right) = (1, *nums.iter().max().unwrap());
+    while left < right {
+        let mid = (left + right) / 2;
+        let mut operations = 0;
+        for num in nums.iter() {
+            operations += (num - 1) / mid;
+        }
+        if operations <= max_operations {
+            right = mid;
+        } else {
+            left = mid + 1;
+        }
+    }
+    left
+}
+pub fn part_two(input: &str) -> Option<u32> {
+    let cubes = parse_input(input);
+    let (min, max) = cubes.iter().fold(
+        (
+            Point3::new(i64::MAX, i64::MAX, i64::MAX),
+            Point3::new(i64::MIN, i64::MIN, i64::MIN),
+        ),
+        |(min, max), cube| {
+            (
+                Point3::new(min.x.min(cube.x), min.y.min(cube.y), min.z.min(cube.z)),
+                Point3::new(max.x.max(cube.x), max.y.max(cube.y), max.z.max(cube.z)),
+            )
+        },
+    );
+    let (min, max) = (min - Point3::new(1, 1, 1), max + Point3::new(1, 1, 1));
+    let in_bounds = |cube: &Point3| {
+        (min.x..=max.x).contains(&cube.x)
+            && (min.y..=max.y).contains(&cube.y)
+            && (min.z..=max.z).contains(&cube.z)
+    };
+
+    let cube_set: HashSet<_> = cubes.into_iter().collect();
+    let mut outside = HashSet::new();
+    let mut queue = VecDeque::new();
+    queue.push_back(min);
+    while let Some(cube) = queue.pop_front() {
+        for neighbour in cube.neighbours() {
+            if in_bounds(&neighbour) && !cube_set.contains(&neighbour) && outside.insert(neighbour)
+            {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    let mut ans = 0;
+    for cube in cube_set {
+        for neighbour in cube.neighbours() {
+            if outside.contains(&neighbour) {
+                ans += 1;
+            }
+        }
+    }
+    Some(ans)
+}
+
+fn parse_cube(s: &str) -> Point3 {
+    let nums = s
+        .split(',')
+        .map(|s| s.parse::<i64>().unwrap())
+        .collect::<Vec<_>>();
+    Point3::new(nums[0], nums[1], nums[2])
+}
+
+fn parse_input(input: &str) -> Vec<Point3> {
+    input.lines().map(parse_cube).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 18);
+        assert_eq!(part_one(&input), Some(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 18);
+        assert_eq!(part_two(&input), Some(58));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::helpers::{Direction, Grid, Point2};
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let (mut board, commands) = parse_input(input);
+    for command in commands {
+        board.step(&command, 1);
+    }
+    Some(board.password())
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    let (mut board, commands) = parse_input(input);
+    for command in commands {
+        board.step(&command, 2);
+    }
+    Some(board.password())
+}
+
+/// The facing of the password: 0 for right, 1 for down, 2 for left and 3 for up.
+fn score(facing: Direction) -> i64 {
+    match facing {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Board {
+    grid: Grid<char>,
+    facing: Direction,
+    cur_pos: Point2,
+    cube_size: i64,
+}
+
+impl Board {
+    fn password(&self) -> i64 {
+        (self.cur_pos.y + 1) * 1000 + (self.cur_pos.x + 1) * 4 + score(self.facing)
+    }
+
+    fn tile(&self, pos: Point2) -> char {
+        self.grid[pos.position().unwrap()]
+    }
+
+    fn step(&mut self, command: &Command, part: u32) {
+        match command {
+            Command::TurnLeft => self.facing = self.facing.turn_left(),
+            Command::TurnRight => self.facing = self.facing.turn_right(),
+            Command::Move(num) => {
+                for _ in 0..*num {
+                    self.cur_pos = self.move_direction(self.cur_pos, self.facing, part);
+                }
+            }
+        }
+    }
+
+    fn move_direction(&mut self, cur: Point2, dir: Direction, part: u32) -> Point2 {
+        let mut new_pos = cur;
+
+        loop {
+            new_pos += dir.offset();
+            let mut new_dir = dir;
+            if part == 1 {
+                new_pos = self.wrap_around_part1(new_pos);
+            } else {
+                (new_pos, new_dir) = self.wrap_around_part2(new_pos, dir);
+            }
+
+            if self.tile(new_pos) == '#' {
+                new_pos = self.move_direction(new_pos, self.facing.opposite(), part);
+                self.facing = dir;
+                break;
+            }
+            if self.tile(new_pos) == '.' {
+                self.facing = new_dir;
+                break;
+            }
+        }
+        new_pos
+    }
+
+    fn wrap_around_part1(&self, cur: Point2) -> Point2 {
+        Point2::from(self.grid.wrap((cur.y as isize, cur.x as isize)))
+    }
+
+    fn wrap_around_part2(&mut self, mut cur: Point2, dir: Direction) -> (Point2, Direction) {
+        /*  hard code state transition..
+        _ 1 2
+        _ 3 _
+        4 5 _
+        6 _ _
+        */
+        if cur.y < 0 {
+            cur.y += 1;
+        } else if cur.y >= self.grid.height() as i64 {
+            cur.y -= 1
+        } else if cur.x < 0 {
+            cur.x += 1;
+        } else if cur.x >= self.grid.width() as i64 {
+            cur.x -= 1;
+        } else if self.tile(cur) != ' ' {
+            return (cur, dir);
+        }
+
+        let (new_row, new_col, new_facing) =
+            match (cur.y / self.cube_size, cur.x / self.cube_size, dir) {
+                (0, 0, Direction::Left) => (2, 0, Direction::Right),
+                (0, 1, Direction::Up) => (3, 0, Direction::Right),
+                (0, 2, Direction::Up) => (3, 0, Direction::Up),
+                (0, 2, Direction::Right) => (2, 1, Direction::Left),
+                (1, 2, Direction::Down) => (1, 1, Direction::Left),
+                (1, 0, Direction::Left) => (2, 0, Direction::Down),
+                (1, 2, Direction::Right) => (0, 2, Direction::Up),
+                (2, 2, Direction::Right) => (0, 2, Direction::Left),
+                (3, 1, Direction::Down) => (3, 0, Direction::Left),
+                (2, 0, Direction::Left) => (0, 1, Direction::Right),
+                (1, 0, Direction::Up) => (1, 1, Direction::Right),
+                (3, 0, Direction::Left) => (0, 1, Direction::Down),
+                (3, 1, Direction::Right) => (2, 1, Direction::Up),
+                (3, 0, Direction::Down) => (0, 2, Direction::Down),
+                _ => unreachable!(),
+            };
+        let (dr, dc) = (cur.y % self.cube_size, cur.x % self.cube_size);
+        let t = match dir {
+            Direction::Up => dc,
+            Direction::Down => self.cube_size - dc - 1,
+            Direction::Left => self.cube_size - dr - 1,
+            Direction::Right => dr,
+        };
+        let (tr, tc) = match new_facing {
+            Direction::Up => (self.cube_size - 1, t),
+            Direction::Down => (0, self.cube_size - t - 1),
+            Direction::Left => (self.cube_size - t - 1, self.cube_size - 1),
+            Direction::Right => (t, 0),
+        };
+        self.facing = new_facing;
+        let new_pos = Point2::new(new_col * self.cube_size + tc, new_row * self.cube_size + tr);
+        (new_pos, new_facing)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Command {
+    TurnLeft,
+    TurnRight,
+    Move(i64),
+}
+fn parse_input(input: &str) -> (Board, Vec<Command>) {
+    let mut rows = Vec::new();
+    let mut lines = input.lines();
+    let mut max_column = 0;
+    // build map
+    for line in lines.by_ref() {
+        let mut row = Vec::new();
+        if line.is_empty() {
+            break;
+        }
+        for c in line.chars() {
+            row.push(c);
+        }
+        max_column = max_column.max(row.len());
+        rows.push(row);
+    }
+
+    let mut cube_size = i64::MAX;
+    for row in rows.iter_mut() {
+        row.resize(max_column, ' ');
+        cube_size = cube_size.min(row.iter().filter(|&c| *c == ' ').count() as i64);
+    }
+
+    let grid = Grid::from_rows(rows);
+    let start_pos = grid.row(0).iter().position(|&c| c == '.').unwrap_or(0);
+    let board = Board {
+        grid,
+        facing: Direction::Right,
+        cur_pos: Point2::new(start_pos as i64, 0),
+        cube_size,
+    };
+    // parse command
+    let mut commands = Vec::new();
+    let row = lines.next().unwrap();
+    let mut num = 0;
+    for c in row.chars() {
+        match c {
+            'L' => {
+                if num != 0 {
+                    commands.push(Command::Move(num));
+                    num = 0;
+                }
+                commands.push(Command::TurnLeft)
+            }
+            'R' => {
+                if num != 0 {
+                    commands.push(Command::Move(num));
+                    num = 0;
+                }
+                commands.push(Command::TurnRight)
+            }
+            c if c.is_alphanumeric() => {
+                num = num * 10 + c.to_digit(10).unwrap() as i64;
+            }
+            _ => unreachable!(),
+        }
+    }
+    if num > 0 {
+        commands.push(Command::Move(num));
+    }
+    (board, commands)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 22);
+        assert_eq!(part_one(&input), Some(6032));
+    }
+
+    // #[test]
+    // fn test_part_two() {
+    //     let input = crate::read_file("examples", 22);
+    //     assert_eq!(part_two(&input), None);
+    // }
+
+    #[test]
+    fn test_parse_input() {
+        let input = crate::read_file("examples", 22);
+        let (board, commands) = parse_input(&input);
+        assert_eq!(board.grid.height(), 12);
+        assert_eq!(commands.len(), 13);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::helpers::{Direction, Direction8, Point2};
+use crate::params::Params;
+use std::collections::{HashMap, VecDeque};
+
+pub fn part_one(input: &str) -> Option<i64> {
+    let (params, input) = Params::split(input);
+    let mut grove = Grove::from(input);
+    grove.simulate(params.get_or("rounds", 10));
+    let res = grove.score();
+    Some(res)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (_, input) = Params::split(input);
+    let mut grove = Grove::from(input);
+    Some(grove.simulate_until_stable())
+}
+
+/// The order in which elves consider moving at the start.
+fn check_order() -> VecDeque<Direction> {
+    VecDeque::from([
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ])
+}
+
+/// The three positions that must be free for an elf to move in `direction`.
+fn check(direction: Direction, pos: Point2) -> [Point2; 3] {
+    let direction = Direction8::from(direction);
+    [direction, direction.turn_left(), direction.turn_right()].map(|d| pos + d.offset())
+}
+
+#[derive(Debug)]
+struct Elve {
+    pos: Point2,
+    propose: Point2,
+    check_order: VecDeque<Direction>,
+}
+
+#[derive(Debug)]
+struct Grove {
+    elves: Vec<Elve>,
+    proposes: HashMap<Point2, u32>,
+}
+
+impl From<&str> for Grove {
+    fn from(s: &str) -> Self {
+        let elves = s
+            .lines()
+            .map(|line| line.chars().collect::<Vec<_>>())
+            .enumerate()
+            .flat_map(|(x, line)| {
+                line.into_iter()
+                    .enumerate()
+                    .map(move |(y, c)| (Point2::new(y as i64, x as i64), c))
+            })
+            .filter(|(_, c)| *c == '#')
+            .map(|(pos, _)| Elve {
+                pos,
+                propose: Point2::ORIGIN,
+                check_order: check_order(),
+            })
+            .collect::<Vec<_>>();
+
+        Self {
+            elves,
+            proposes: HashMap::new(),
+        }
+    }
+}
+
+impl Grove {
+    fn propose(&mut self) {
+        let cur_pos = self
+            .elves
+            .iter()
+            .map(|e| (e.pos, 1))
+            .collect::<HashMap<_, _>>();
+        let mut propose_map = HashMap::<Point2, u32>::new();
+        for elve in &mut self.elves {
+            let mut proposed = elve.pos;
+            if elve
+                .pos
+                .neighbours8()
+                .iter()
+                .all(|pos| !cur_pos.contains_key(pos))
+            {
+                elve.propose = proposed;
+            } else {
+                for dir in elve.check_order.clone().into_iter() {
+                    if check(dir, elve.pos)
+                        .iter()
+                        .all(|pos| !cur_pos.contains_key(pos))
+                    {
+                        proposed = elve.pos + dir.offset();
+                        break;
+                    }
+                }
+            }
+            elve.propose = proposed;
+            let dir = elve.check_order.pop_front().unwrap();
+            elve.check_order.push_back(dir);
+            *propose_map.entry(proposed).or_default() += 1;
+        }
+        self.proposes = propose_map;
+    }
+
+    fn move_elves(&mut self) {
+        for elve in &mut self.elves {
+            if let Some(count) = self.proposes.get(&elve.propose) {
+                if *count > 1 {
+                    elve.propose = elve.pos;
+                }
+            }
+        }
+        for elve in &mut self.elves {
+            elve.pos = elve.propose;
+        }
+        self.proposes.clear();
+    }
+
+    fn simulate(&mut self, round: u32) {
+        for _ in 0..round {
+            self.propose();
+            self.move_elves();
+        }
+    }
+
+    /// The smallest rectangle containing all elves, as `(x_min, x_max, y_min, y_max)`.
+    fn bounds(&self) -> (i64, i64, i64, i64) {
+        self.elves.iter().fold(
+            (i64::MAX, i64::MIN, i64::MAX, i64::MIN),
+            |(x_min, x_max, y_min, y_max), elve| {
+                (
+                    x_min.min(elve.pos.x),
+                    x_max.max(elve.pos.x),
+                    y_min.min(elve.pos.y),
+                    y_max.max(elve.pos.y),
+                )
+            },
+        )
+    }
+
+    fn simulate_until_stable(&mut self) -> u32 {
+        let mut count = 0;
+        loop {
+            self.propose();
+            if self.elves.iter().all(|elve| elve.pos == elve.propose) {
+                break;
+            }
+            self.move_elves();
+            count += 1;
+        }
+        count + 1
+    }
+
+    fn score(&self) -> i64 {
+        let (x_min, x_max, y_min, y_max) = self.bounds();
+        (x_max - x_min + 1) * (y_max - y_min + 1) - self.elves.len() as i64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 23);
+        assert_eq!(part_one(&input), Some(110));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 23);
+        assert_eq!(part_two(&input), Some(20));
+    }
+
+    #[test]
+    fn test_rounds() {
+        // the small example, whose 5 elves are spread over 6 rows and 5 columns after 3 rounds.
+        let input = "#! rounds = 3\n.....\n..##.\n..#..\n.....\n..##.\n.....\n";
+        assert_eq!(part_one(input), Some(6 * 5 - 5));
+    }
+}
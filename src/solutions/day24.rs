@@ -0,0 +1,96 @@
+use crate::helpers::search;
+use crate::helpers::{Grid, Point2};
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let valley = Valley::from(input);
+    valley.schedule(valley.start, valley.end, 0)
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let valley = Valley::from(input);
+    let time = valley.schedule(valley.start, valley.end, 0)?;
+    let time = valley.schedule(valley.end, valley.start, time)?;
+    valley.schedule(valley.start, valley.end, time)
+}
+
+#[derive(Debug)]
+struct Valley {
+    grid: Grid<char>,
+    start: Point2,
+    end: Point2,
+}
+
+impl Valley {
+    /// The minute at which we reach `end` at the earliest, leaving `start` at minute `time`.
+    fn schedule(&self, start: Point2, end: Point2, time: u32) -> Option<u32> {
+        let path = search::bfs(
+            [(start, time)],
+            |&(pos, time)| {
+                let time = time + 1;
+                pos.neighbours()
+                    .into_iter()
+                    .chain([pos])
+                    .filter(move |&next| self.is_free(next, time))
+                    .map(move |next| (next, time))
+            },
+            |&(pos, _)| pos == end,
+        )?;
+        path.last().map(|&(_, time)| time)
+    }
+
+    /// Whether `pos` is neither a wall nor covered by a blizzard at minute `time`.
+    fn is_free(&self, pos: Point2, time: u32) -> bool {
+        if pos
+            .position()
+            .and_then(|pos| self.grid.get(pos))
+            .is_none_or(|c| *c == '#')
+        {
+            return false;
+        }
+        // the inner area, without the walls around it.
+        let (width, height) = (self.grid.width() as i64 - 2, self.grid.height() as i64 - 2);
+        if pos.y == 0 || pos.y > height {
+            // the start or the end, which no blizzard reaches.
+            return true;
+        }
+        // blizzards wrap around, so check where each kind would have come from.
+        let time = time as i64;
+        let wrap = |v: i64, len: i64| (v - 1).rem_euclid(len) + 1;
+        let at = |x: i64, y: i64| self.grid[Point2::new(x, y).position().unwrap()];
+        at(wrap(pos.x - time, width), pos.y) != '>'
+            && at(wrap(pos.x + time, width), pos.y) != '<'
+            && at(pos.x, wrap(pos.y - time, height)) != 'v'
+            && at(pos.x, wrap(pos.y + time, height)) != '^'
+    }
+}
+
+impl From<&str> for Valley {
+    fn from(s: &str) -> Self {
+        let grid = Grid::parse(s, |c| c);
+        let last = grid.height() - 1;
+        let start = grid.row(0).iter().position(|&c| c == '.').unwrap_or(0);
+        let end = grid.row(last).iter().position(|&c| c == '.').unwrap_or(0);
+        Valley {
+            start: Point2::from((0, start)),
+            end: Point2::from((last, end)),
+            grid,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::read_file("examples", 24);
+        assert_eq!(part_one(&input), Some(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = crate::read_file("examples", 24);
+        assert_eq!(part_two(&input), Some(54));
+    }
+}
@@ -0,0 +1,110 @@
+/*
+ * Every day's solution lives in its own module and is registered in `SOLUTIONS` below.
+ * Days of other years than the default year live in a `yearYYYY` module, e.g. `year2021::day01`.
+ * `cargo scaffold <day>` adds new days to this file automatically.
+ */
+use crate::DEFAULT_YEAR;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+/// A solver for one part of a puzzle, with its answer converted to a string.
+pub type Solver = fn(&str) -> Option<String>;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Solution {
+    /// Both parts with their part number.
+    pub fn parts(&self) -> [(u8, Solver); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
+
+    /// `Day 01`, prefixed with the year for days that are not of the default year.
+    pub fn label(&self) -> String {
+        label(self.year, self.day)
+    }
+}
+
+/// `Day 01`, prefixed with the year for days that are not of the default year.
+pub fn label(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("Day {:02}", day)
+    } else {
+        format!("{} Day {:02}", year, day)
+    }
+}
+
+macro_rules! solution {
+    ($year:expr, $day:expr, $($module:ident)::+) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part_one: |input| $($module)::+::part_one(input).map(|answer| answer.to_string()),
+            part_two: |input| $($module)::+::part_two(input).map(|answer| answer.to_string()),
+        }
+    };
+    ($day:expr, $module:ident) => {
+        solution!(DEFAULT_YEAR, $day, $module)
+    };
+}
+
+pub static SOLUTIONS: &[Solution] = &[
+    solution!(1, day01),
+    solution!(2, day02),
+    solution!(3, day03),
+    solution!(4, day04),
+    solution!(5, day05),
+    solution!(6, day06),
+    solution!(7, day07),
+    solution!(8, day08),
+    solution!(9, day09),
+    solution!(10, day10),
+    solution!(11, day11),
+    solution!(12, day12),
+    solution!(13, day13),
+    solution!(14, day14),
+    solution!(15, day15),
+    solution!(16, day16),
+    solution!(17, day17),
+    solution!(18, day18),
+    solution!(19, day19),
+    solution!(20, day20),
+    solution!(21, day21),
+    solution!(22, day22),
+    solution!(23, day23),
+    solution!(24, day24),
+    solution!(25, day25),
+];
+
+pub fn get(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.year == year && solution.day == day)
+}
@@ -0,0 +1,416 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+const HEADER: &str = "timestamp,year,day,part,outcome,wait_until,answer";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// The answer was not checked because the last submission was too recent.
+    TooRecent,
+    /// The part was already solved.
+    AlreadySolved,
+}
+
+impl Outcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too_high",
+            Outcome::TooLow => "too_low",
+            Outcome::Wrong => "wrong",
+            Outcome::TooRecent => "too_recent",
+            Outcome::AlreadySolved => "already_solved",
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+            Outcome::TooRecent,
+            Outcome::AlreadySolved,
+        ]
+        .into_iter()
+        .find(|outcome| outcome.as_str() == s)
+        .ok_or_else(|| format!("unknown outcome \"{}\"", s))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Outcome::Correct => "That's the right answer!",
+            Outcome::TooHigh => "That's not the right answer, your answer is too high.",
+            Outcome::TooLow => "That's not the right answer, your answer is too low.",
+            Outcome::Wrong => "That's not the right answer.",
+            Outcome::TooRecent => "You gave an answer too recently.",
+            Outcome::AlreadySolved => "This part is already solved.",
+        };
+        write!(f, "{}", message)
+    }
+}
+
+/// The server's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    /// How long to wait before the next submission.
+    pub wait: Option<Duration>,
+}
+
+fn number(word: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    word.parse()
+        .ok()
+        .or_else(|| WORDS.iter().position(|w| *w == word).map(|i| i as u64 + 1))
+}
+
+/// Parses the page returned after posting an answer.
+/// Returns `None` if the page doesn't contain a known message.
+pub fn parse_response(html: &str) -> Option<Response> {
+    lazy_static! {
+        static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+        static ref WAIT_MINUTES: Regex = Regex::new(r"wait (\w+) minutes?").unwrap();
+        static ref LEFT_TO_WAIT: Regex =
+            Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    }
+
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let text = TAG.replace_all(article, "");
+
+    let outcome = if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Outcome::TooHigh
+        } else if text.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::TooRecent
+    } else if text.contains("Did you already complete it") {
+        Outcome::AlreadySolved
+    } else {
+        return None;
+    };
+
+    let wait = if let Some(captures) = LEFT_TO_WAIT.captures(&text) {
+        let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+        let seconds: u64 = captures[2].parse().unwrap();
+        Some(Duration::from_secs(minutes * 60 + seconds))
+    } else {
+        WAIT_MINUTES
+            .captures(&text)
+            .and_then(|captures| number(&captures[1]))
+            .map(|minutes| Duration::from_secs(minutes * 60))
+    };
+
+    Some(Response { outcome, wait })
+}
+
+/// One submitted answer, as stored in the submissions file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    /// Seconds since the unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch until which no answer may be submitted.
+    pub wait_until: Option<u64>,
+    pub answer: String,
+}
+
+impl Submission {
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{}",
+            self.timestamp,
+            self.year,
+            self.day,
+            self.part,
+            self.outcome.as_str(),
+            self.wait_until.map(|t| t.to_string()).unwrap_or_default(),
+            self.answer
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Self> {
+        // the answer comes last, so it may contain commas.
+        let fields: Vec<&str> = line.splitn(7, ',').collect();
+        if fields.len() != 7 {
+            return None;
+        }
+        Some(Self {
+            timestamp: fields[0].parse().ok()?,
+            year: fields[1].parse().ok()?,
+            day: fields[2].parse().ok()?,
+            part: fields[3].parse().ok()?,
+            outcome: fields[4].parse().ok()?,
+            wait_until: match fields[5] {
+                "" => None,
+                t => Some(t.parse().ok()?),
+            },
+            answer: fields[6].to_string(),
+        })
+    }
+}
+
+/// Why an answer is not submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved,
+    /// The answer was submitted before and was wrong.
+    KnownWrong(Outcome),
+    /// A previous answer was too high and this one is not lower, or vice versa.
+    OutOfBounds {
+        outcome: Outcome,
+        bound: String,
+    },
+    Cooldown(Duration),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved => write!(f, "this part was already solved."),
+            Refusal::KnownWrong(outcome) => {
+                write!(f, "this answer was submitted before. {}", outcome)
+            }
+            Refusal::OutOfBounds { outcome, bound } => write!(
+                f,
+                "\"{}\" was already {}, so this answer can't be right.",
+                bound,
+                if *outcome == Outcome::TooHigh {
+                    "too high"
+                } else {
+                    "too low"
+                }
+            ),
+            Refusal::Cooldown(wait) => write!(
+                f,
+                "the last answer was submitted too recently. Wait {}s before submitting again.",
+                wait.as_secs()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Submissions {
+    /// Submissions in the order they were made.
+    pub entries: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn parse(contents: &str) -> Self {
+        Self {
+            entries: contents
+                .lines()
+                .filter(|line| *line != HEADER)
+                .filter_map(Submission::from_line)
+                .collect(),
+        }
+    }
+
+    /// Loads the submissions file, treating a missing file as no submissions.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Appends a submission to the submissions file, creating it if needed.
+    pub fn append(path: &Path, submission: &Submission) -> io::Result<()> {
+        let is_new = !path.exists();
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if is_new {
+            writeln!(file, "{}", HEADER)?;
+        }
+        writeln!(file, "{}", submission.to_line())
+    }
+
+    /// Checks whether `answer` may be submitted at `now` (seconds since the unix epoch).
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        let previous: Vec<&Submission> = self
+            .entries
+            .iter()
+            .filter(|s| s.year == year && s.day == day && s.part == part)
+            .collect();
+
+        if previous.iter().any(|s| s.outcome == Outcome::Correct) {
+            return Err(Refusal::AlreadySolved);
+        }
+        if let Some(s) = previous
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_wrong())
+        {
+            return Err(Refusal::KnownWrong(s.outcome));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            for s in previous.iter() {
+                let out_of_bounds = match (s.outcome, s.answer.parse::<i64>()) {
+                    (Outcome::TooHigh, Ok(bound)) => value >= bound,
+                    (Outcome::TooLow, Ok(bound)) => value <= bound,
+                    _ => false,
+                };
+                if out_of_bounds {
+                    return Err(Refusal::OutOfBounds {
+                        outcome: s.outcome,
+                        bound: s.answer.clone(),
+                    });
+                }
+            }
+        }
+
+        match self.entries.iter().filter_map(|s| s.wait_until).max() {
+            Some(until) if until > now => Err(Refusal::Cooldown(Duration::from_secs(until - now))),
+            _ => Ok(()),
+        }
+    }
+}
+
+pub fn submissions_path() -> PathBuf {
+    crate::input::crate_root().join("submissions.csv")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn submission(part: u8, outcome: Outcome, answer: &str, wait_until: Option<u64>) -> Submission {
+        Submission {
+            timestamp: 100,
+            year: 2022,
+            day: 1,
+            part,
+            outcome,
+            wait_until,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <em>one gold star</em> closer."
+            )),
+            Some(Response {
+                outcome: Outcome::Correct,
+                wait: None
+            })
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, ... please wait one minute before trying again.")),
+            Some(Response {
+                outcome: Outcome::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too low. Please wait 5 minutes before trying again.")),
+            Some(Response {
+                outcome: Outcome::TooLow,
+                wait: Some(Duration::from_secs(300))
+            })
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 34s left to wait.")),
+            Some(Response {
+                outcome: Outcome::TooRecent,
+                wait: Some(Duration::from_secs(94))
+            })
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .map(|r| r.outcome),
+            Some(Outcome::AlreadySolved)
+        );
+        assert_eq!(parse_response(&page("Something else")), None);
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let s = submission(2, Outcome::Wrong, "a,b", Some(160));
+        let contents = format!("{}\n{}\n", HEADER, s.to_line());
+        assert_eq!(Submissions::parse(&contents).entries, vec![s]);
+    }
+
+    #[test]
+    fn test_check() {
+        let submissions = Submissions {
+            entries: vec![
+                submission(1, Outcome::Correct, "24000", None),
+                submission(2, Outcome::TooHigh, "500", None),
+                submission(2, Outcome::TooLow, "100", Some(160)),
+            ],
+        };
+        let check = |part, answer, now| submissions.check(2022, 1, part, answer, now);
+
+        assert_eq!(check(1, "1", 200), Err(Refusal::AlreadySolved));
+        assert_eq!(
+            check(2, "100", 200),
+            Err(Refusal::KnownWrong(Outcome::TooLow))
+        );
+        assert!(matches!(
+            check(2, "600", 200),
+            Err(Refusal::OutOfBounds {
+                outcome: Outcome::TooHigh,
+                ..
+            })
+        ));
+        assert_eq!(
+            check(2, "300", 130),
+            Err(Refusal::Cooldown(Duration::from_secs(30)))
+        );
+        assert_eq!(check(2, "300", 200), Ok(()));
+        assert_eq!(check(2, "abc", 200), Ok(()));
+    }
+}
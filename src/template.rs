@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use lazy_static::lazy_static;
+use regex::{Captures, Regex};
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// The template `cargo scaffold` uses when no `--template` is given.
+pub const DEFAULT_TEMPLATE: &str = "default";
+
+#[derive(Debug)]
+pub enum TemplateError {
+    NotFound {
+        name: String,
+        available: Vec<String>,
+    },
+    /// A `{{placeholder}}` that has no value.
+    UnknownPlaceholder {
+        name: String,
+        placeholder: String,
+    },
+    Io {
+        name: String,
+        error: io::Error,
+    },
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::NotFound { name, available } => write!(
+                f,
+                "there is no template \"{}\" in \"{}\". Available templates: {}.",
+                name,
+                templates_dir().display(),
+                available.join(", ")
+            ),
+            TemplateError::UnknownPlaceholder { name, placeholder } => write!(
+                f,
+                "template \"{}\" uses the unknown placeholder {{{{{}}}}}.",
+                name, placeholder
+            ),
+            TemplateError::Io { name, error } => {
+                write!(f, "could not read template \"{}\": {}", name, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// A solution template from `templates/<name>.rs`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pub name: String,
+    pub contents: String,
+}
+
+impl Template {
+    pub fn load(name: &str) -> Result<Self, TemplateError> {
+        match fs::read_to_string(template_path(name)) {
+            Ok(contents) => Ok(Self {
+                name: name.to_string(),
+                contents,
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(TemplateError::NotFound {
+                name: name.to_string(),
+                available: available(),
+            }),
+            Err(error) => Err(TemplateError::Io {
+                name: name.to_string(),
+                error,
+            }),
+        }
+    }
+
+    /// Replaces every `{{placeholder}}` with its value. Fails on placeholders without a
+    /// value, so a typo in a template doesn't end up in a generated solution.
+    pub fn render(&self, values: &HashMap<&str, String>) -> Result<String, TemplateError> {
+        lazy_static! {
+            static ref PLACEHOLDER: Regex = Regex::new(r"\{\{\s*([a-z_]+)\s*\}\}").unwrap();
+        }
+
+        if let Some(captures) = PLACEHOLDER
+            .captures_iter(&self.contents)
+            .find(|c| !values.contains_key(&c[1]))
+        {
+            return Err(TemplateError::UnknownPlaceholder {
+                name: self.name.clone(),
+                placeholder: captures[1].to_string(),
+            });
+        }
+
+        let rendered =
+            PLACEHOLDER.replace_all(&self.contents, |c: &Captures| values[&c[1]].clone());
+        Ok(rendered.to_string())
+    }
+}
+
+pub fn templates_dir() -> PathBuf {
+    crate::input::crate_root().join("templates")
+}
+
+fn template_path(name: &str) -> PathBuf {
+    templates_dir().join(format!("{}.rs", name))
+}
+
+/// The names of all templates in `templates/`, sorted.
+pub fn available() -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(templates_dir())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            match path.extension() {
+                Some(extension) if extension == "rs" => {
+                    Some(path.file_stem()?.to_string_lossy().to_string())
+                }
+                _ => None,
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("day", "7".to_string()),
+            ("day_padded", "07".to_string()),
+            ("year", "2022".to_string()),
+            ("part_one_type", "u32".to_string()),
+            ("part_two_type", "u64".to_string()),
+            ("part_one_expected", "Some(95437)".to_string()),
+            ("part_two_expected", "None".to_string()),
+            (
+                "read_example",
+                "crate::read_file(\"examples\", 7)".to_string(),
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_render() {
+        let template = Template {
+            name: "test".to_string(),
+            contents: "// day {{day}} ({{ day_padded }}) of {{year}}: {{part_one_type}}"
+                .to_string(),
+        };
+        assert_eq!(
+            template.render(&values()).unwrap(),
+            "// day 7 (07) of 2022: u32"
+        );
+    }
+
+    #[test]
+    fn test_unknown_placeholder() {
+        let template = Template {
+            name: "test".to_string(),
+            contents: "{{day}} {{part_three_type}}".to_string(),
+        };
+        assert!(matches!(
+            template.render(&values()),
+            Err(TemplateError::UnknownPlaceholder { placeholder, .. }) if placeholder == "part_three_type"
+        ));
+    }
+
+    #[test]
+    fn test_templates() {
+        let names = available();
+        assert!(names.contains(&DEFAULT_TEMPLATE.to_string()));
+        for name in names {
+            let rendered = Template::load(&name).unwrap().render(&values()).unwrap();
+            // templates that don't use the input yet take it as `_input`.
+            assert!(
+                rendered.contains("pub fn part_one(input: &str) -> Option<u32>")
+                    || rendered.contains("pub fn part_one(_input: &str) -> Option<u32>")
+            );
+            assert!(rendered.contains("assert_eq!(part_one(&input), Some(95437));"));
+        }
+        assert!(matches!(
+            Template::load("missing"),
+            Err(TemplateError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,203 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::runner::PartResult;
+use crate::{ANSI_BOLD, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::{fs, io};
+
+const HEADER: &str =
+    "# Known answers for the real puzzle inputs, one `<day>[/<input>] <part> <answer>` per line.";
+
+type Key = (u8, Option<String>, u8);
+
+/// Expected answers per day, input and part, stored in `src/answers.txt`.
+/// Answers for a named input are keyed as `<day>/<input>`, e.g. `01/alice 1 24000`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers(BTreeMap<Key, String>);
+
+fn parse_key(day: &str, part: &str) -> Option<Key> {
+    let (day, input) = match day.split_once('/') {
+        Some((day, input)) if !input.is_empty() => (day, Some(input.to_string())),
+        Some(_) => return None,
+        None => (day, None),
+    };
+    Some((day.parse().ok()?, input, part.parse().ok()?))
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let mut answers = BTreeMap::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(3, char::is_whitespace);
+            let parsed = match (parts.next(), parts.next(), parts.next()) {
+                (Some(day), Some(part), Some(answer)) => {
+                    parse_key(day, part).map(|key| (key, answer.trim().to_string()))
+                }
+                _ => None,
+            };
+            match parsed {
+                Some((key, answer)) => {
+                    answers.insert(key, answer);
+                }
+                None => return Err(format!("invalid answer on line {}: \"{}\"", i + 1, line)),
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    /// Loads the answers file, treating a missing file as no known answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, day: u8, input: Option<&str>, part: u8) -> Option<&str> {
+        self.0
+            .get(&(day, input.map(str::to_string), part))
+            .map(String::as_str)
+    }
+
+    pub fn insert(&mut self, day: u8, input: Option<&str>, part: u8, answer: String) {
+        self.0
+            .insert((day, input.map(str::to_string), part), answer);
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", HEADER)?;
+        for ((day, input, part), answer) in self.0.iter() {
+            match input {
+                Some(input) => writeln!(f, "{:02}/{} {} {}", day, input, part, answer)?,
+                None => writeln!(f, "{:02} {} {}", day, part, answer)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// There is no input or no expected answer to check against.
+    Missing(&'static str),
+}
+
+impl Verdict {
+    pub fn is_fail(&self) -> bool {
+        matches!(self, Verdict::Fail { .. })
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "{}PASS{}", ANSI_BOLD, ANSI_RESET),
+            Verdict::Fail { expected, actual } => write!(
+                f,
+                "{}FAIL{} (expected {}, got {})",
+                ANSI_BOLD,
+                ANSI_RESET,
+                expected,
+                actual.as_deref().unwrap_or("no answer")
+            ),
+            Verdict::Missing(reason) => {
+                write!(f, "{}MISSING{} ({})", ANSI_BOLD, ANSI_RESET, reason)
+            }
+        }
+    }
+}
+
+/// Checks a part's result against its expected answer.
+pub fn verify(expected: Option<&str>, result: &PartResult) -> Verdict {
+    match expected {
+        None => Verdict::Missing("no expected answer"),
+        Some(expected) if result.is_solved() && result.answer == expected => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.to_string(),
+            actual: result.is_solved().then(|| result.answer.clone()),
+        },
+    }
+}
+
+/// The known answers of `year`: `src/answers.txt`, or `src/<year>/answers.txt` for other years.
+pub fn answers_path(year: u16) -> PathBuf {
+    crate::input::year_dir(year).join("answers.txt")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::Status;
+    use crate::DEFAULT_YEAR;
+    use std::time::Duration;
+
+    fn result(answer: &str, status: Status) -> PartResult {
+        PartResult {
+            year: DEFAULT_YEAR,
+            day: 1,
+            part: 1,
+            input: None,
+            answer: answer.to_string(),
+            elapsed: Duration::ZERO,
+            status,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers =
+            Answers::parse("# comment\n01 1 24000\n5 2 MCD\n\n25 1 2=-1=0\n01/alice 1 7\n")
+                .unwrap();
+        assert_eq!(answers.get(1, None, 1), Some("24000"));
+        assert_eq!(answers.get(5, None, 2), Some("MCD"));
+        assert_eq!(answers.get(25, None, 1), Some("2=-1=0"));
+        assert_eq!(answers.get(1, Some("alice"), 1), Some("7"));
+        assert_eq!(answers.get(2, None, 1), None);
+        assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+
+        assert!(Answers::parse("01 x 24000").is_err());
+        assert!(Answers::parse("01 1").is_err());
+        assert!(Answers::parse("01/ 1 24000").is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        assert_eq!(
+            verify(Some("42"), &result("42", Status::Solved)),
+            Verdict::Pass
+        );
+        assert!(verify(Some("42"), &result("41", Status::Solved)).is_fail());
+        assert_eq!(
+            verify(Some("42"), &result("", Status::Unsolved)),
+            Verdict::Fail {
+                expected: "42".to_string(),
+                actual: None
+            }
+        );
+        assert_eq!(
+            verify(None, &result("42", Status::Solved)),
+            Verdict::Missing("no expected answer")
+        );
+    }
+}
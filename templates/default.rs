@@ -0,0 +1,24 @@
+pub fn part_one(_input: &str) -> Option<{{part_one_type}}> {
+    None
+}
+
+pub fn part_two(_input: &str) -> Option<{{part_two_type}}> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = {{read_example}};
+        assert_eq!(part_one(&input), {{part_one_expected}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = {{read_example}};
+        assert_eq!(part_two(&input), {{part_two_expected}});
+    }
+}
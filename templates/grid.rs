@@ -0,0 +1,33 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use crate::helpers::Grid;
+
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c)
+}
+
+pub fn part_one(input: &str) -> Option<{{part_one_type}}> {
+    let _grid = parse_input(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{part_two_type}}> {
+    let _grid = parse_input(input);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = {{read_example}};
+        assert_eq!(part_one(&input), {{part_one_expected}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = {{read_example}};
+        assert_eq!(part_two(&input), {{part_two_expected}});
+    }
+}
@@ -0,0 +1,55 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref LINE_REGEX: Regex = Regex::new(r"(?P<name>\w+) (?P<value>-?\d+)").unwrap();
+}
+
+#[derive(Debug)]
+#[allow(dead_code)] // until the solution reads the fields.
+struct Line {
+    name: String,
+    value: i64,
+}
+
+impl From<&str> for Line {
+    fn from(s: &str) -> Self {
+        let caps = LINE_REGEX.captures(s).unwrap();
+        Line {
+            name: caps["name"].to_string(),
+            value: caps["value"].parse().unwrap(),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<Line> {
+    input.lines().map(Line::from).collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{part_one_type}}> {
+    let _lines = parse_input(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{part_two_type}}> {
+    let _lines = parse_input(input);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = {{read_example}};
+        assert_eq!(part_one(&input), {{part_one_expected}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = {{read_example}};
+        assert_eq!(part_two(&input), {{part_two_expected}});
+    }
+}
@@ -0,0 +1,54 @@
+// https://adventofcode.com/{{year}}/day/{{day}}
+use crate::params::Params;
+
+#[derive(Debug, Clone)]
+struct State {
+    tick: usize,
+}
+
+impl From<&str> for State {
+    fn from(_input: &str) -> Self {
+        State { tick: 0 }
+    }
+}
+
+impl State {
+    /// Advances the simulation by one step.
+    fn step(&mut self) {
+        self.tick += 1;
+    }
+}
+
+pub fn part_one(input: &str) -> Option<{{part_one_type}}> {
+    // set `#! steps = <n>` in the example if it runs for fewer steps than the real input.
+    let (params, input) = Params::split(input);
+    let steps = params.get_or("steps", 10);
+    let mut state = State::from(input);
+    for _ in 0..steps {
+        state.step();
+    }
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{part_two_type}}> {
+    let (_params, input) = Params::split(input);
+    let _state = State::from(input);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = {{read_example}};
+        assert_eq!(part_one(&input), {{part_one_expected}});
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = {{read_example}};
+        assert_eq!(part_two(&input), {{part_two_expected}});
+    }
+}